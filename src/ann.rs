@@ -19,7 +19,18 @@ pub struct AnnuityDetails {
     pub last_payout_epoch: u64,
 }
 
+// ACTUS rate reset terms (RR): the rate is re-fixed every cycle from the oracle's reference rate
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct RateResetTerms {
+    pub rate_oracle: ComponentAddress,
+    pub cycle_of_rate_reset: u64,
+    pub rate_spread: Decimal,
+    pub rate_floor: Decimal,
+    pub rate_cap: Decimal,
+}
+
 #[blueprint]
+#[events(PandaoEvent)]
 mod annuity {
 
     struct Annuity {
@@ -37,7 +48,11 @@ mod annuity {
         price: Decimal,
         annual_payout: Decimal,
         last_payout_epoch: u64,
-        resource_address_of_anns : ResourceAddress
+        resource_address_of_anns : ResourceAddress,
+        // penalty applied to a surrender refund at issuance, decays linearly to zero at maturity
+        surrender_penalty: Decimal,
+        rate_reset: Option<RateResetTerms>,
+        next_rate_reset: u64,
     }
 
     impl Annuity {
@@ -53,7 +68,24 @@ mod annuity {
             annuity_position: String,
             price: Decimal,
             number_of_annuities_to_mint: Decimal,
+            surrender_penalty: Decimal,
+            rate_reset: Option<RateResetTerms>,
         ) -> Global<Annuity> {
+            assert!(
+                surrender_penalty >= Decimal::zero() && surrender_penalty <= Decimal::one(),
+                "surrender penalty must be between 0 and 1"
+            );
+
+            let mut next_rate_reset = maturity_date;
+
+            if let Some(terms) = &rate_reset {
+                assert!(terms.cycle_of_rate_reset > 0, "rate reset cycle must be greater than zero");
+                assert!(terms.rate_floor <= terms.rate_cap, "rate floor can not be above the rate cap");
+
+                next_rate_reset = initial_exchange_date + terms.cycle_of_rate_reset;
+            }
+
+
             let bucket_of_annuities: Bucket = ResourceBuilder::new_fungible(OwnerRole::None)
                 .divisibility(DIVISIBILITY_NONE)
                 .metadata(metadata!(
@@ -85,7 +117,10 @@ mod annuity {
                 price,
                 annual_payout,
                 last_payout_epoch: initial_exchange_date,
-                resource_address_of_anns : ra_ann
+                resource_address_of_anns : ra_ann,
+                surrender_penalty,
+                rate_reset,
+                next_rate_reset,
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::None)
@@ -124,6 +159,8 @@ mod annuity {
                 "Invalid annuity resource."
             );
 
+            self.apply_due_rate_reset();
+
             // let current_epoch =
             //     Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch as u64;
 
@@ -143,10 +180,7 @@ mod annuity {
 
             if years_elapsed >= 1 {
 
-                let interest_payment =
-                    self.notional_principal * self.nominal_interest_rate / Decimal::from(500) ;
-
-                let total_payout = self.annual_payout + interest_payment;
+                let total_payout = self.periodic_payout();
 
                 let payout = self.collected_xrd.take(total_payout);
 
@@ -199,6 +233,189 @@ mod annuity {
                 (annuity_token, empty_bucket)
             }
         }
+
+        // returns the ANN token to the issuer in exchange for its present value minus the surrender penalty
+        pub fn surrender_annuity(&mut self, annuity_token: Bucket) -> Bucket {
+            assert!(
+                annuity_token.amount() == Decimal::one(),
+                "You can only surrender one annuity (ANN) at a time."
+            );
+
+            assert!(
+                annuity_token.resource_address() == self.annuities.resource_address(),
+                "Invalid annuity resource."
+            );
+
+            self.apply_due_rate_reset();
+
+            let current_time_seconds = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            assert!(
+                current_time_seconds < self.maturity_date as i64,
+                "The annuity has matured and can no longer be surrendered."
+            );
+
+            let present_value = self.present_value_of_remaining_payouts();
+
+            let penalty_rate = self.surrender_penalty_at(current_time_seconds);
+
+            let penalty = present_value * penalty_rate;
+
+            let refund_amount = present_value - penalty;
+
+            assert!(
+                self.collected_xrd.amount() >= refund_amount,
+                "The payout reserve does not hold enough XRD to fund this surrender."
+            );
+
+            self.annuities.put(annuity_token);
+
+            let refund = self.collected_xrd.take(refund_amount);
+
+            let event_metadata = AnnuitySurrendered {
+                present_value,
+                penalty_rate,
+                penalty,
+                refund: refund_amount,
+                surrendered_at: current_time_seconds as u64,
+                remaining_reserve: self.collected_xrd.amount(),
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::ANNUITY_SURRENDERED,
+                dao_type: DaoType::Insurance,
+                component_address : Runtime::global_address(),
+                meta_data: DaoEvent::AnnuitySurrendered(event_metadata)
+            });
+
+            refund
+        }
+
+        pub fn get_surrender_value(&self) -> Decimal {
+            let current_time_seconds = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let present_value = self.present_value_of_remaining_payouts();
+
+            present_value - present_value * self.surrender_penalty_at(current_time_seconds)
+        }
+
+        // re-fixes the nominal rate from the oracle once the reset date has passed
+        pub fn reset_rate(&mut self) -> Decimal {
+            assert!(self.rate_reset.is_some(), "This annuity pays a fixed rate.");
+
+            let current_time_seconds = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch as u64;
+
+            assert!(
+                current_time_seconds >= self.next_rate_reset,
+                "The next rate reset is not due yet."
+            );
+
+            self.apply_due_rate_reset();
+
+            self.nominal_interest_rate
+        }
+
+        pub fn get_nominal_interest_rate(&self) -> Decimal {
+            self.nominal_interest_rate
+        }
+
+        pub fn get_next_rate_reset(&self) -> Option<u64> {
+            self.rate_reset.as_ref().map(|_| self.next_rate_reset)
+        }
+
+        fn apply_due_rate_reset(&mut self) {
+            let terms = match &self.rate_reset {
+                Some(terms) => terms.clone(),
+                None => return,
+            };
+
+            let current_time_seconds = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch as u64;
+
+            if current_time_seconds < self.next_rate_reset || self.next_rate_reset >= self.maturity_date {
+                return;
+            }
+
+            let oracle: Global<AnyComponent> = Global::from(terms.rate_oracle);
+            let reference_rate: Decimal = oracle.call_raw("get_rate", scrypto_args!());
+
+            let mut new_rate = reference_rate + terms.rate_spread;
+
+            if new_rate < terms.rate_floor {
+                new_rate = terms.rate_floor;
+            }
+            if new_rate > terms.rate_cap {
+                new_rate = terms.rate_cap;
+            }
+
+            let previous_rate = self.nominal_interest_rate;
+
+            self.nominal_interest_rate = new_rate;
+
+            // skip the cycles that passed without anyone triggering a reset
+            while self.next_rate_reset <= current_time_seconds {
+                self.next_rate_reset += terms.cycle_of_rate_reset;
+            }
+
+            let event_metadata = AnnuityRateReset {
+                reference_rate,
+                previous_rate,
+                new_rate,
+                reset_at: current_time_seconds,
+                next_reset_at: self.next_rate_reset,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::ANNUITY_RATE_RESET,
+                dao_type: DaoType::Insurance,
+                component_address : Runtime::global_address(),
+                meta_data: DaoEvent::AnnuityRateReset(event_metadata)
+            });
+        }
+
+        // annual payout plus interest paid per ANN token each year
+        fn periodic_payout(&self) -> Decimal {
+            let interest_payment =
+                self.notional_principal * self.nominal_interest_rate / Decimal::from(500);
+
+            self.annual_payout + interest_payment
+        }
+
+        // remaining yearly payouts discounted at the nominal interest rate
+        fn present_value_of_remaining_payouts(&self) -> Decimal {
+            let seconds_in_year: u64 = 365 * 24 * 60 * 60;
+
+            let remaining_payouts = self.maturity_date.saturating_sub(self.last_payout_epoch) / seconds_in_year;
+
+            let discount_step = Decimal::one() + self.nominal_interest_rate / Decimal::from(100);
+
+            let payout = self.periodic_payout();
+
+            let mut discount_factor = Decimal::one();
+            let mut present_value = Decimal::zero();
+
+            for _ in 0..remaining_payouts {
+                discount_factor = discount_factor * discount_step;
+                present_value += payout / discount_factor;
+            }
+
+            present_value
+        }
+
+        fn surrender_penalty_at(&self, current_time_seconds: i64) -> Decimal {
+            let term = self.maturity_date as i64 - self.initial_exchange_date as i64;
+
+            if term <= 0 || current_time_seconds >= self.maturity_date as i64 {
+                return Decimal::zero();
+            }
+
+            if current_time_seconds <= self.initial_exchange_date as i64 {
+                return self.surrender_penalty;
+            }
+
+            let time_to_maturity = self.maturity_date as i64 - current_time_seconds;
+
+            self.surrender_penalty * Decimal::from(time_to_maturity) / Decimal::from(term)
+        }
     }
 }
 
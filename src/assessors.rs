@@ -0,0 +1,99 @@
+use scrypto::prelude::*;
+
+// a claim handed to a small committee of assessors instead of a full token vote
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ClaimReview {
+    pub review_id: u64,
+    pub pool_name: String,
+    pub policy_id: NonFungibleLocalId,
+    pub claimant: ComponentAddress,
+    // reported loss, the policy terms decide the payout
+    pub amount: Decimal,
+    pub incident_category: String,
+    pub incident_time: i64,
+    pub assessors: Vec<ComponentAddress>,
+    // stake each assessor had when drawn, their vote counts with this weight
+    pub weights: HashMap<ComponentAddress, Decimal>,
+    // true approves the claim
+    pub votes: HashMap<ComponentAddress, bool>,
+    pub fee_resource: ResourceAddress,
+    pub fee: Decimal,
+    pub deadline: i64,
+}
+
+impl ClaimReview {
+    // None while nobody voted, stake-weighted ties reject the claim
+    pub fn majority(&self) -> Option<bool> {
+        if self.votes.is_empty() {
+            return None;
+        }
+
+        let (approving, total) = self.votes.iter().fold(
+            (Decimal::zero(), Decimal::zero()),
+            |(approving, total), (assessor, approve)| {
+                let weight = self.weights.get(assessor).cloned().unwrap_or(Decimal::zero());
+
+                if *approve {
+                    (approving + weight, total + weight)
+                } else {
+                    (approving, total + weight)
+                }
+            },
+        );
+
+        Some(approving * 2 > total)
+    }
+
+    pub fn all_voted(&self) -> bool {
+        !self.assessors.is_empty() && self.votes.len() == self.assessors.len()
+    }
+}
+
+// draws `size` distinct assessors, each seat picked with probability proportional to stake
+pub fn select_committee(
+    candidates: &Vec<(ComponentAddress, Decimal)>,
+    seed: Hash,
+    size: usize,
+) -> Vec<(ComponentAddress, Decimal)> {
+    let mut remaining: Vec<(ComponentAddress, Decimal)> = candidates
+        .iter()
+        .filter(|(_, stake)| *stake > Decimal::zero())
+        .cloned()
+        .collect();
+
+    let mut committee = Vec::new();
+
+    for seat in 0..size {
+        if remaining.is_empty() {
+            break;
+        }
+
+        let total = remaining
+            .iter()
+            .fold(Decimal::zero(), |total, (_, stake)| total + *stake);
+
+        let mut seat_seed = seed.0.to_vec();
+        seat_seed.extend_from_slice(&(seat as u64).to_le_bytes());
+
+        let mut draw_bytes = [0u8; 8];
+        draw_bytes.copy_from_slice(&hash(seat_seed).0[..8]);
+
+        let point = total * Decimal::from(u64::from_le_bytes(draw_bytes)) / Decimal::from(u64::MAX);
+
+        let mut cumulative = Decimal::zero();
+        let mut chosen = remaining.len() - 1;
+
+        for (index, (_, stake)) in remaining.iter().enumerate() {
+            cumulative += *stake;
+
+            if point < cumulative {
+                chosen = index;
+                break;
+            }
+        }
+
+        committee.push(remaining.remove(chosen));
+    }
+
+    committee
+}
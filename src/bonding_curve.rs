@@ -0,0 +1,99 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub enum BondingCurveShape {
+    // price = base_price + slope * supply
+    Linear { slope: Decimal },
+
+    // price = base_price * e^(growth_rate * supply)
+    Exponential { growth_rate: Decimal },
+}
+
+// Prices community tokens by circulating supply. `reserve_ratio` of every purchase is kept
+// aside as the buy-back reserve; selling back along the same curve scaled by that ratio
+// means the reserve always covers every outstanding token.
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct BondingCurve {
+    pub shape: BondingCurveShape,
+    pub base_price: Decimal,
+    pub reserve_ratio: Decimal,
+}
+
+impl BondingCurve {
+    pub fn assert_valid(&self) {
+        assert!(self.base_price > Decimal::zero(), "bonding curve base price must be positive");
+        assert!(
+            self.reserve_ratio > Decimal::zero() && self.reserve_ratio <= Decimal::one(),
+            "reserve ratio must be greater than 0 and at most 1"
+        );
+
+        match &self.shape {
+            BondingCurveShape::Linear { slope } => {
+                assert!(*slope >= Decimal::zero(), "bonding curve slope can not be negative")
+            }
+            BondingCurveShape::Exponential { growth_rate } => {
+                assert!(*growth_rate >= Decimal::zero(), "bonding curve growth rate can not be negative")
+            }
+        }
+    }
+
+    pub fn spot_price(&self, supply: Decimal) -> Decimal {
+        match &self.shape {
+            BondingCurveShape::Linear { slope } => self.base_price + *slope * supply,
+            BondingCurveShape::Exponential { growth_rate } => self.base_price * exp(*growth_rate * supply),
+        }
+    }
+
+    // area under the curve between `supply` and `supply + amount`
+    pub fn purchase_cost(&self, supply: Decimal, amount: Decimal) -> Decimal {
+        match &self.shape {
+            BondingCurveShape::Linear { slope } => {
+                self.base_price * amount
+                    + *slope * amount * (supply * Decimal::from(2) + amount) / Decimal::from(2)
+            }
+            BondingCurveShape::Exponential { growth_rate } => {
+                if growth_rate.is_zero() {
+                    return self.base_price * amount;
+                }
+
+                self.base_price / *growth_rate
+                    * (exp(*growth_rate * (supply + amount)) - exp(*growth_rate * supply))
+            }
+        }
+    }
+
+    // what the reserve pays for `amount` tokens when circulating supply is `supply`
+    pub fn sale_value(&self, supply: Decimal, amount: Decimal) -> Decimal {
+        assert!(amount <= supply, "can not sell more tokens than are in circulation");
+
+        self.purchase_cost(supply - amount, amount) * self.reserve_ratio
+    }
+}
+
+// e^x for non-negative x: halve until below one, sum the Taylor series, then square back up
+fn exp(x: Decimal) -> Decimal {
+    let mut reduced = x;
+    let mut squarings = 0;
+
+    while reduced > Decimal::one() {
+        reduced = reduced / Decimal::from(2);
+        squarings += 1;
+    }
+
+    let mut term = Decimal::one();
+    let mut sum = Decimal::one();
+
+    for i in 1..30 {
+        term = term * reduced / Decimal::from(i);
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+    }
+
+    for _ in 0..squarings {
+        sum = sum * sum;
+    }
+
+    sum
+}
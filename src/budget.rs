@@ -0,0 +1,76 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BudgetCategory {
+    Claims,
+    Investments,
+    Operations,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub enum BudgetPeriod {
+    // the cap resets every given number of epochs
+    Epochs(u64),
+
+    // the cap resets every 30 days
+    Monthly,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct BudgetLimit {
+    pub cap: Decimal,
+    pub period: BudgetPeriod,
+    pub spent_in_period: Decimal,
+    pub period_index: u64,
+}
+
+impl BudgetLimit {
+    pub fn new(cap: Decimal, period: BudgetPeriod) -> Self {
+        let period_index = current_period_index(&period);
+
+        Self {
+            cap,
+            period,
+            spent_in_period: Decimal::zero(),
+            period_index,
+        }
+    }
+
+    pub fn remaining(&self) -> Decimal {
+        if current_period_index(&self.period) != self.period_index {
+            return self.cap;
+        }
+
+        if self.cap > self.spent_in_period {
+            self.cap - self.spent_in_period
+        } else {
+            Decimal::zero()
+        }
+    }
+
+    pub fn charge(&mut self, amount: Decimal) {
+        let period_index = current_period_index(&self.period);
+
+        if period_index != self.period_index {
+            self.period_index = period_index;
+            self.spent_in_period = Decimal::zero();
+        }
+
+        assert!(
+            self.spent_in_period + amount <= self.cap,
+            "this proposal exceeds the remaining budget for its category"
+        );
+
+        self.spent_in_period += amount;
+    }
+}
+
+fn current_period_index(period: &BudgetPeriod) -> u64 {
+    match period {
+        BudgetPeriod::Epochs(length) => Runtime::current_epoch().number() / (*length).max(1),
+        BudgetPeriod::Monthly => {
+            let seconds_in_month: i64 = 30 * 24 * 60 * 60;
+            (Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch / seconds_in_month) as u64
+        }
+    }
+}
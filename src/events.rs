@@ -1,4 +1,14 @@
 use scrypto::prelude::*;
+use crate::ann::RateResetTerms;
+use crate::bonding_curve::BondingCurve;
+use crate::vesting::{VestingAllocation, VestingReceipt};
+use crate::streaming::PaymentStream;
+use crate::budget::{BudgetCategory, BudgetPeriod};
+use crate::membership::MemberBadge;
+use crate::insurance::{CoveragePolicy, CoverageTerms, PayoutBreakdown, PolicyTransferability, UnderwritingRules};
+use crate::oracle::ParametricTrigger;
+use crate::assessors::ClaimReview;
+use crate::reinsurance::ReinsuranceTreaty;
 
 #[allow(non_camel_case_types)]
 #[derive(ScryptoSbor, ScryptoEvent)]
@@ -27,7 +37,79 @@ pub enum EventType {
 
     ANNUAL_PAYOUT_CLAIMED,
 
-    ANNUAL_PAYOUT_COULD_NOT_BE_CLAIMED
+    ANNUAL_PAYOUT_COULD_NOT_BE_CLAIMED,
+
+    ANNUITY_SURRENDERED,
+
+    REFERENCE_RATE_UPDATED,
+
+    ANNUITY_RATE_RESET,
+
+    PREMIUM_PAID,
+
+    REDEMPTION_QUEUED,
+
+    VESTING_CREATED,
+
+    VESTED_TOKENS_CLAIMED,
+
+    PAYMENT_STREAM_CREATED,
+
+    PAYMENT_STREAM_CANCELLED,
+
+    MEMBER_BADGE_ISSUED,
+
+    MEMBER_REWARDS_DISTRIBUTED,
+
+    POOL_SWAP,
+
+    TREASURY_REBALANCED,
+
+    STAKED,
+
+    UNSTAKED,
+
+    STAKING_REWARDS_CLAIMED,
+
+    STAKE_SLASHED,
+
+    POLICY_PURCHASED,
+
+    POLICY_CLAIM_PAID,
+
+    CAPACITY_REACHED,
+
+    ORACLE_VALUE_REPORTED,
+
+    POLICY_RENEWED,
+
+    POLICY_LAPSED,
+
+    CLAIM_DISPUTED,
+
+    APPEAL_RESOLVED,
+
+    ASSESSOR_REGISTERED,
+
+    ASSESSOR_WITHDRAWN,
+
+    CLAIM_REVIEW_OPENED,
+
+    CLAIM_REVIEW_FINALIZED,
+
+    REINSURANCE_OFFERED,
+
+    REINSURANCE_BOUND,
+
+    REINSURANCE_DRAWN,
+
+    POLICY_TRANSFERRED,
+
+    BENEFICIARY_DESIGNATED,
+
+    PARAMETRIC_CLAIM_SETTLED,
+
+    ASSESSOR_DELISTED
 }
 
 #[derive(ScryptoSbor, ScryptoEvent)]
@@ -63,6 +145,8 @@ pub struct TokenWightedDeployment {
     pub purpose: String,
 
     pub proposal_creation_right: ProposalCreationRight,
+
+    pub bonding_curve: Option<BondingCurve>,
 }
 
 #[derive(ScryptoSbor, ScryptoEvent)]
@@ -94,6 +178,8 @@ pub struct PraposalMetadata {
     pub proposal_id: usize,
     pub governance_token_or_owner_token_address: ResourceAddress,
     pub token_type: VotingType,
+    pub action: Option<ProposalAction>,
+    pub linked_proposal_id: Option<usize>,
 }
 
 #[derive(ScryptoSbor, ScryptoEvent)]
@@ -122,7 +208,71 @@ pub enum DaoEvent {
 
                                           // ProposalCreationRightAdmin
 
-    ClaimAnnualPayout(ClaimAnnualPayout)
+    ClaimAnnualPayout(ClaimAnnualPayout),
+
+    AnnuitySurrendered(AnnuitySurrendered),
+
+    ReferenceRateUpdate(ReferenceRateUpdate),
+
+    AnnuityRateReset(AnnuityRateReset),
+
+    ProposalActionExecuted(ProposalActionExecuted),
+
+    PremiumPaid(PremiumPaid),
+
+    RedemptionQueued(RedemptionRequest),
+
+    VestingCreated(VestingReceipt),
+
+    VestedTokensClaimed(VestedTokensClaimed),
+
+    PaymentStreamCreated(PaymentStream),
+
+    PaymentStreamCancelled(PaymentStream),
+
+    MemberBadgeIssued(MemberBadge),
+
+    MemberRewardsDistributed(MemberRewardsDistributed),
+
+    PoolSwap(PoolSwap),
+
+    TreasuryRebalanced(TreasuryRebalanced),
+
+    StakeChanged(StakeChanged),
+
+    StakingRewardsClaimed(StakingRewardsClaimed),
+
+    StakeSlashed(StakeSlashed),
+
+    PolicyPurchased(CoveragePolicy),
+
+    PolicyClaimPaid(PolicyClaimPaid),
+
+    CapacityReached(CapacityReached),
+
+    OracleValueReported(OracleValueReported),
+
+    PolicyRenewed(PolicyRenewed),
+
+    PolicyLapsed(PolicyLapsed),
+
+    ClaimDisputed(ClaimDispute),
+
+    AppealResolved(AppealResolved),
+
+    AssessorStakeChanged(StakeChanged),
+
+    ClaimReviewOpened(ClaimReview),
+
+    ClaimReviewFinalized(ClaimReviewFinalized),
+
+    ReinsuranceTreatyUpdated(ReinsuranceTreaty),
+
+    ReinsuranceDrawn(ReinsuranceDrawn),
+
+    PolicyOwnershipChanged(PolicyOwnershipChanged),
+
+    ParametricClaimSettled(ParametricClaimSettled)
 }
 
 // #[derive(ScryptoSbor, ScryptoEvent)]
@@ -143,6 +293,21 @@ pub struct PraposalExecute {
                             // pub purchased_amount : Decimal
 }
 
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ProposalActionExecuted {
+    pub praposal_address: ComponentAddress,
+    pub proposal_id: usize,
+    pub action: ProposalAction,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PremiumPaid {
+    pub payer: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+    pub total_premiums_collected: Decimal,
+}
+
 #[derive(ScryptoSbor, ScryptoEvent)]
 pub struct ProposalVote {
     pub praposal_address: ComponentAddress,
@@ -185,16 +350,185 @@ pub struct TreasuryContribution {
     pub timestamp: u64,
 }
 
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct ContributorRecord {
+    pub total_contributed: Decimal,
+    pub number_of_contributions: u64,
+    pub first_contribution_at: u64,
+    pub last_contribution_at: u64,
+}
+
+#[derive(ScryptoSbor, Debug)]
+pub struct TreasuryReport {
+    pub balances: HashMap<ResourceAddress, Decimal>,
+    pub inflows: HashMap<ResourceAddress, Decimal>,
+    pub outflows: HashMap<ResourceAddress, Decimal>,
+    pub committed_obligations: Decimal,
+    pub reported_at: u64,
+}
+
 #[allow(non_camel_case_types)]
-#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
 pub enum ProposalCreationRight {
     EVERYONE,
     TOKEN_HOLDER_THRESHOLD(Decimal),
     ADMIN,
 }
 
+// treasury operations a passed proposal can carry out
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub enum ProposalAction {
+    TransferAsset {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        recipient: ComponentAddress,
+    },
+
+    PayClaim {
+        claimant: ComponentAddress,
+        amount: Decimal,
+    },
+
+    WhitelistAsset(ResourceAddress),
+
+    SetStableAsset(ResourceAddress),
+
+    SetClaimReserveRatio(Decimal),
+
+    MintVested {
+        allocations: Vec<VestingAllocation>,
+    },
+
+    CreateStream {
+        recipient: ComponentAddress,
+        resource_address: ResourceAddress,
+        rate_per_second: Decimal,
+        start_time: i64,
+        end_time: i64,
+    },
+
+    CancelStream {
+        stream_id: u64,
+    },
+
+    SetBudget {
+        category: BudgetCategory,
+        // caps are per asset, amounts in different assets are never added up
+        resource_address: ResourceAddress,
+        cap: Decimal,
+        period: BudgetPeriod,
+    },
+
+    RemoveBudget(BudgetCategory, ResourceAddress),
+
+    DistributeMemberRewards {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+
+    CreateRiskPool {
+        name: String,
+        premium_rate: Decimal,
+        max_coverage: Decimal,
+        max_exposure_multiple: Decimal,
+        parametric_trigger: Option<ParametricTrigger>,
+        transferability: PolicyTransferability,
+        terms: CoverageTerms,
+        underwriting: UnderwritingRules,
+    },
+
+    UpdateRiskPool {
+        name: String,
+        premium_rate: Decimal,
+        max_coverage: Decimal,
+        max_exposure_multiple: Decimal,
+    },
+
+    FundRiskPool {
+        name: String,
+        amount: Decimal,
+    },
+
+    DefundRiskPool {
+        name: String,
+        amount: Decimal,
+    },
+
+    // `amount` is the reported loss, the policy terms decide the payout
+    PayPolicyClaim {
+        pool_name: String,
+        policy_id: NonFungibleLocalId,
+        amount: Decimal,
+        incident_category: String,
+        incident_time: i64,
+    },
+
+    ConfigureAppeals {
+        bond_ratio: Decimal,
+        quorum_multiplier: u8,
+        voting_period_seconds: i64,
+    },
+
+    ConfigureAssessors {
+        committee_size: usize,
+        review_window_seconds: i64,
+        review_fee: Decimal,
+        slash_ratio: Decimal,
+        minimum_stake: Decimal,
+    },
+
+    OfferReinsurance {
+        ceding_dao: ComponentAddress,
+        premium: Decimal,
+        attachment_point: Decimal,
+        limit: Decimal,
+        duration_seconds: i64,
+    },
+
+    BuyReinsurance {
+        reinsurer: ComponentAddress,
+        treaty_id: u64,
+        premium: Decimal,
+    },
+
+    ConfigureStaking {
+        reward_share: Decimal,
+        cooldown_seconds: i64,
+    },
+
+    RebalanceTreasury {
+        pool: ComponentAddress,
+        input_resource: ResourceAddress,
+        input_amount: Decimal,
+        // absolute floor fixed when the proposal is made, not derived from the price at execution
+        min_output: Decimal,
+    },
+
+    ApprovePolicyTransfer {
+        pool_name: String,
+        policy_id: NonFungibleLocalId,
+        new_holder: ComponentAddress,
+    },
+
+    SetIbnrRatio(Decimal),
+
+    WhitelistSwapPool(ComponentAddress),
+
+    // surrender one ANN token of this issuer held in the treasury
+    SurrenderAnnuity(ComponentAddress),
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct RedemptionRequest {
+    pub request_id: u64,
+    pub account: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount_owed: Decimal,
+    pub requested_at: u64,
+}
+
 #[allow(non_camel_case_types)]
-#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
 pub enum VotingType {
     ResourceHold,
     Equality,
@@ -234,6 +568,8 @@ pub struct AnnuityTokenCreation {
     pub price: Decimal,
     pub number_of_annuities_to_mint: Decimal,
     pub your_address: ComponentAddress,
+    pub surrender_penalty: Decimal,
+    pub rate_reset: Option<RateResetTerms>,
 }
 
 #[derive(ScryptoSbor, ScryptoEvent)]
@@ -260,3 +596,181 @@ pub struct ClaimAnnualPayout {
     pub prev_payout_claimed_at : Option<u64>,
     pub remaining_time_to_next_payout : i64
 }
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AnnuitySurrendered {
+    pub present_value : Decimal,
+    pub penalty_rate : Decimal,
+    pub penalty : Decimal,
+    pub refund : Decimal,
+    pub surrendered_at : u64,
+    pub remaining_reserve : Decimal
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ReferenceRateUpdate {
+    pub description : String,
+    pub previous_rate : Decimal,
+    pub new_rate : Decimal,
+    pub updated_at : u64
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AnnuityRateReset {
+    pub reference_rate : Decimal,
+    pub previous_rate : Decimal,
+    pub new_rate : Decimal,
+    pub reset_at : u64,
+    pub next_reset_at : u64
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct VestedTokensClaimed {
+    pub receipt_id : NonFungibleLocalId,
+    pub beneficiary : ComponentAddress,
+    pub amount : Decimal,
+    pub claimed_amount : Decimal,
+    pub total_amount : Decimal
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct MemberRewardsDistributed {
+    pub resource_address : ResourceAddress,
+    pub amount : Decimal,
+    pub number_of_members : usize
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PoolSwap {
+    pub input_resource : ResourceAddress,
+    pub input_amount : Decimal,
+    pub output_resource : ResourceAddress,
+    pub output_amount : Decimal
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct TreasuryRebalanced {
+    pub pool : ComponentAddress,
+    pub input_resource : ResourceAddress,
+    pub input_amount : Decimal,
+    pub output_resource : ResourceAddress,
+    pub output_amount : Decimal,
+    pub minimum_output : Decimal
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct StakeChanged {
+    pub staker : ComponentAddress,
+    pub amount : Decimal,
+    pub total_staked : Decimal
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct StakingRewardsClaimed {
+    pub staker : ComponentAddress,
+    pub rewards : HashMap<ResourceAddress, Decimal>
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct StakeSlashed {
+    pub staker : ComponentAddress,
+    pub tokens_slashed : Decimal,
+    pub value_slashed : Decimal,
+    pub claimant : ComponentAddress
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PolicyClaimPaid {
+    pub pool_name : String,
+    pub policy_id : NonFungibleLocalId,
+    pub claimant : ComponentAddress,
+    pub incident_category : String,
+    pub amount_claimed : Decimal,
+    pub amount_paid : Decimal,
+    pub breakdown : PayoutBreakdown
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct CapacityReached {
+    pub pool_name : String,
+    pub active_exposure : Decimal,
+    pub capacity_limit : Decimal
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct OracleValueReported {
+    pub description : String,
+    pub value : Decimal,
+    pub observed_at : i64
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PolicyRenewed {
+    pub pool_name : String,
+    pub policy_id : NonFungibleLocalId,
+    pub premium_paid : Decimal,
+    pub new_end_time : i64
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PolicyLapsed {
+    pub pool_name : String,
+    pub policy_id : NonFungibleLocalId,
+    pub holder : ComponentAddress,
+    pub lapsed_at : i64
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ClaimDispute {
+    pub rejected_proposal_id : usize,
+    pub appeal_proposal_id : usize,
+    pub claimant : ComponentAddress,
+    pub bond_resource : ResourceAddress,
+    pub bond_amount : Decimal,
+    pub appeal_end_time : i64
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct AppealResolved {
+    pub appeal_proposal_id : usize,
+    pub rejected_proposal_id : usize,
+    pub claimant : ComponentAddress,
+    pub upheld : bool,
+    pub bond_amount : Decimal
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ClaimReviewFinalized {
+    pub review_id : u64,
+    pub approved : bool,
+    pub amount : Decimal,
+    pub rewarded_assessors : Vec<ComponentAddress>,
+    pub fee_per_assessor : Decimal,
+    pub slashed_assessors : HashMap<ComponentAddress, Decimal>
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ReinsuranceDrawn {
+    pub treaty_id : u64,
+    pub reinsurer : ComponentAddress,
+    pub ceding_dao : ComponentAddress,
+    pub amount : Decimal,
+    pub total_drawn : Decimal
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PolicyOwnershipChanged {
+    pub pool_name : String,
+    pub policy_id : NonFungibleLocalId,
+    pub holder : ComponentAddress,
+    pub beneficiary : ComponentAddress
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct ParametricClaimSettled {
+    pub pool_name : String,
+    pub policy_id : NonFungibleLocalId,
+    pub trigger_value : Decimal,
+    pub observed_at : i64,
+    pub amount_claimed : Decimal
+}
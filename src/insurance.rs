@@ -0,0 +1,870 @@
+use scrypto::prelude::*;
+use crate::events::*;
+use crate::pricing::*;
+use crate::oracle::{Observation, ParametricTrigger};
+
+// cost-sharing and limits a pool writes into every policy it sells
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct CoverageTerms {
+    // borne by the holder on every claim
+    pub deductible: Decimal,
+    // share of the loss above the deductible the holder keeps, between 0 and 1
+    pub coinsurance: Decimal,
+    pub per_incident_limit: Decimal,
+    // most the policy pays within one policy year
+    pub annual_aggregate_limit: Decimal,
+    pub exclusions: Vec<String>,
+}
+
+// how a reported loss turned into a payout, one reduction per step
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct PayoutBreakdown {
+    pub loss_amount: Decimal,
+    pub deductible_applied: Decimal,
+    pub coinsurance_applied: Decimal,
+    pub per_incident_limit_applied: Decimal,
+    pub aggregate_limit_applied: Decimal,
+    pub coverage_limit_applied: Decimal,
+    pub payout: Decimal,
+}
+
+impl CoverageTerms {
+    pub fn assert_valid(&self) {
+        assert!(self.deductible >= Decimal::zero(), "deductible can not be negative");
+        assert!(
+            self.coinsurance >= Decimal::zero() && self.coinsurance < Decimal::one(),
+            "co-insurance must be at least 0 and below 1"
+        );
+        assert!(self.per_incident_limit > Decimal::zero(), "per-incident limit must be positive");
+        assert!(self.annual_aggregate_limit > Decimal::zero(), "annual aggregate limit must be positive");
+    }
+
+    pub fn excludes(&self, incident_category: &String) -> bool {
+        self.exclusions.contains(incident_category)
+    }
+
+    // deductible, then co-insurance, then the per-incident, annual aggregate and remaining coverage limits
+    pub fn compute_payout(&self, loss_amount: Decimal, claimed_in_year: Decimal, remaining_coverage: Decimal) -> PayoutBreakdown {
+        let after_deductible = (loss_amount - self.deductible).max(Decimal::zero());
+        let after_coinsurance = after_deductible * (Decimal::one() - self.coinsurance);
+        let after_incident_limit = after_coinsurance.min(self.per_incident_limit);
+        let after_aggregate_limit =
+            after_incident_limit.min((self.annual_aggregate_limit - claimed_in_year).max(Decimal::zero()));
+        let payout = after_aggregate_limit.min(remaining_coverage.max(Decimal::zero()));
+
+        PayoutBreakdown {
+            loss_amount,
+            deductible_applied: loss_amount - after_deductible,
+            coinsurance_applied: after_deductible - after_coinsurance,
+            per_incident_limit_applied: after_coinsurance - after_incident_limit,
+            aggregate_limit_applied: after_incident_limit - after_aggregate_limit,
+            coverage_limit_applied: after_aggregate_limit - payout,
+            payout,
+        }
+    }
+}
+
+// guards against buying cover right before a known loss
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct UnderwritingRules {
+    // incidents this soon after a policy starts are not covered
+    pub waiting_period_seconds: i64,
+    // most cover one account may buy within a window of `cover_window_epochs`
+    pub max_cover_per_account: Decimal,
+    pub cover_window_epochs: u64,
+}
+
+// data carried by the coverage NFT a pool mints for every policy it sells
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct CoveragePolicy {
+    pub pool_name: String,
+    #[mutable]
+    pub holder: ComponentAddress,
+    // account claim payouts go to
+    #[mutable]
+    pub beneficiary: ComponentAddress,
+    pub coverage_amount: Decimal,
+    pub risk_class: RiskClass,
+    pub terms: CoverageTerms,
+    #[mutable]
+    pub premium_paid: Decimal,
+    pub start_time: i64,
+    #[mutable]
+    pub end_time: i64,
+    #[mutable]
+    pub claimed_amount: Decimal,
+    // policy year, counted from the start, that `claimed_in_year` belongs to
+    #[mutable]
+    pub aggregate_year: i64,
+    #[mutable]
+    pub claimed_in_year: Decimal,
+    // set once a DAO-approved transfer reissued this policy under a new ID
+    #[mutable]
+    pub superseded_by: Option<NonFungibleLocalId>,
+    // (from, until) stretches between expiry and a renewal made in the grace period, not covered
+    #[mutable]
+    pub uncovered_gaps: Vec<(i64, i64)>,
+}
+
+impl CoveragePolicy {
+    pub fn covers(&self, incident_time: i64, waiting_period_seconds: i64) -> bool {
+        incident_time >= self.start_time + waiting_period_seconds
+            && incident_time < self.end_time
+            && !self
+                .uncovered_gaps
+                .iter()
+                .any(|(from, until)| incident_time >= *from && incident_time < *until)
+    }
+}
+
+// one-off permission, minted when the DAO approves a transfer, to reissue a policy to `new_holder`
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct PolicyTransferPermit {
+    pub policy_id: NonFungibleLocalId,
+    pub new_holder: ComponentAddress,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicyTransferability {
+    Transferable,
+    // locked to the account that bought it
+    Soulbound,
+    // never moved; the pool reissues it to a new holder under a permit the DAO votes for
+    DaoApproved,
+}
+
+#[derive(ScryptoSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicyStatus {
+    Active,
+    // expired but still renewable without a gap in cover
+    GracePeriod,
+    Lapsed,
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct RiskPoolInfo {
+    pub name: String,
+    pub capital: Decimal,
+    pub premium_rate: Decimal,
+    pub max_coverage: Decimal,
+    pub max_exposure_multiple: Decimal,
+    pub active_exposure: Decimal,
+    pub utilization: Decimal,
+    pub parametric_trigger: Option<ParametricTrigger>,
+    pub transferability: PolicyTransferability,
+    pub terms: CoverageTerms,
+    pub underwriting: UnderwritingRules,
+    pub total_premiums: Decimal,
+    pub total_claims_paid: Decimal,
+    pub policy_resource: ResourceAddress,
+}
+
+#[blueprint]
+#[events(PandaoEvent)]
+mod insurance_pool {
+
+    enable_method_auth! {
+        methods {
+            purchase_policy => PUBLIC;
+            renew_policy => PUBLIC;
+            process_lapses => PUBLIC;
+            get_policy_status => PUBLIC;
+            add_capital => PUBLIC;
+            get_pool_info => PUBLIC;
+            get_policy => PUBLIC;
+            get_utilization => PUBLIC;
+            quote_premium => PUBLIC;
+            parametric_condition_met => PUBLIC;
+            parametric_trigger_observation => PUBLIC;
+            record_parametric_settlement => restrict_to: [OWNER];
+            assert_claimable => PUBLIC;
+            designate_beneficiary => PUBLIC;
+            transfer_policy => PUBLIC;
+            register_holder => PUBLIC;
+            record_holder => restrict_to: [OWNER];
+            approve_transfer => restrict_to: [OWNER];
+            pay_claim => restrict_to: [OWNER];
+            withdraw_capital => restrict_to: [OWNER];
+            update_parameters => restrict_to: [OWNER];
+        }
+    }
+
+    // one line of cover (smart-contract, health, crop, ...) with its own capital, owned by the DAO
+    struct InsurancePool {
+        dao_address: ComponentAddress,
+        name: String,
+        capital: Vault,
+        // yearly base premium as a fraction of the insured amount, before loadings
+        premium_rate: Decimal,
+        // the most cover this pool may have outstanding at once
+        max_coverage: Decimal,
+        // capital adequacy: outstanding cover may be at most this many times the pool's capital
+        max_exposure_multiple: Decimal,
+        active_exposure: Decimal,
+        // coverage amount and end time of every policy that has not lapsed yet
+        active_policies: HashMap<NonFungibleLocalId, (Decimal, i64)>,
+        // how long after expiry a policy can still be renewed before it lapses
+        grace_period_seconds: i64,
+        total_premiums: Decimal,
+        total_claims_paid: Decimal,
+        // set for parametric lines, whose policies pay out on the oracle reading instead of a vote
+        parametric_trigger: Option<ParametricTrigger>,
+        transferability: PolicyTransferability,
+        terms: CoverageTerms,
+        underwriting: UnderwritingRules,
+        // cover bought per account as (window index, amount bought in that window)
+        account_cover: HashMap<ComponentAddress, (u64, Decimal)>,
+        // triggering observation each parametric policy was already paid out on
+        parametric_settlements: HashMap<NonFungibleLocalId, Observation>,
+        policy_manager: NonFungibleResourceManager,
+        transfer_permit_manager: NonFungibleResourceManager,
+        next_permit_id: u64,
+        next_policy_id: u64,
+    }
+
+    impl InsurancePool {
+        pub fn instantiate_insurance_pool(
+            dao_address: ComponentAddress,
+            name: String,
+            stable_asset: ResourceAddress,
+            premium_rate: Decimal,
+            max_coverage: Decimal,
+            max_exposure_multiple: Decimal,
+            parametric_trigger: Option<ParametricTrigger>,
+            transferability: PolicyTransferability,
+            terms: CoverageTerms,
+            underwriting: UnderwritingRules,
+        ) -> Global<InsurancePool> {
+            let (address_reservation, component_address) =
+                Runtime::allocate_component_address(InsurancePool::blueprint_id());
+
+            let transfer_permit_manager = ResourceBuilder::new_integer_non_fungible::<PolicyTransferPermit>(OwnerRole::None)
+                .metadata(metadata!(init{
+                    "name" => format!("{} transfer permit", &name), locked;
+                }))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
+                .burn_roles(burn_roles!(
+                    burner => rule!(require(global_caller(component_address)));
+                    burner_updater => rule!(deny_all);
+                ))
+                .create_with_no_initial_supply();
+
+            // only freely transferable policies may leave the holder's account
+            let withdraw_rule = match transferability {
+                PolicyTransferability::Transferable => rule!(allow_all),
+                PolicyTransferability::Soulbound | PolicyTransferability::DaoApproved => rule!(deny_all),
+            };
+
+            let policy_manager = ResourceBuilder::new_integer_non_fungible::<CoveragePolicy>(OwnerRole::None)
+                .metadata(metadata!(init{
+                    "name" => format!("{} coverage", &name), locked;
+                }))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
+                .non_fungible_data_update_roles(non_fungible_data_update_roles!(
+                    non_fungible_data_updater => rule!(require(global_caller(component_address)));
+                    non_fungible_data_updater_updater => rule!(deny_all);
+                ))
+                .withdraw_roles(withdraw_roles!(
+                    withdrawer => withdraw_rule;
+                    withdrawer_updater => rule!(deny_all);
+                ))
+                .create_with_no_initial_supply();
+
+            Self {
+                dao_address,
+                name,
+                capital: Vault::new(stable_asset),
+                premium_rate,
+                max_coverage,
+                max_exposure_multiple,
+                active_exposure: Decimal::zero(),
+                active_policies: HashMap::new(),
+                grace_period_seconds: 14 * 24 * 60 * 60,
+                total_premiums: Decimal::zero(),
+                total_claims_paid: Decimal::zero(),
+                parametric_trigger,
+                transferability,
+                terms,
+                underwriting,
+                account_cover: HashMap::new(),
+                parametric_settlements: HashMap::new(),
+                policy_manager,
+                transfer_permit_manager,
+                next_permit_id: 0,
+                next_policy_id: 0,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(global_caller(dao_address)))))
+            .with_address(address_reservation)
+            .globalize()
+        }
+
+        pub fn purchase_policy(
+            &mut self,
+            mut payment: Bucket,
+            coverage_amount: Decimal,
+            duration_seconds: i64,
+            risk_class: RiskClass,
+            beneficiary: Option<ComponentAddress>,
+            mut account: Global<Account>,
+        ) -> Bucket {
+            let owner_role_of_buyer = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_buyer.rule);
+
+            assert!(coverage_amount > Decimal::zero(), "coverage amount must be positive");
+            assert!(duration_seconds > 0, "coverage duration must be positive");
+
+            self.process_lapses();
+
+            self.record_account_cover(account.address(), coverage_amount);
+
+            let premium = self.quote_premium(coverage_amount, duration_seconds, risk_class).premium;
+
+            assert!(payment.amount() >= premium, "you are paying an insufficient premium");
+
+            // the premium joins the capital before the adequacy check, as it backs the new policy too
+            let retained = self.share_premium(payment.take(premium));
+            self.capital.put(retained);
+
+            assert!(
+                self.active_exposure + coverage_amount <= self.capacity_limit(),
+                "this pool has no capacity left for the requested coverage"
+            );
+
+            self.total_premiums += premium;
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let policy_data = CoveragePolicy {
+                pool_name: self.name.clone(),
+                holder: account.address(),
+                beneficiary: beneficiary.unwrap_or(account.address()),
+                coverage_amount,
+                risk_class,
+                terms: self.terms.clone(),
+                premium_paid: premium,
+                start_time: now,
+                end_time: now + duration_seconds,
+                claimed_amount: Decimal::zero(),
+                aggregate_year: 0,
+                claimed_in_year: Decimal::zero(),
+                superseded_by: None,
+                uncovered_gaps: Vec::new(),
+            };
+
+            let policy_id = NonFungibleLocalId::integer(self.next_policy_id);
+            self.next_policy_id += 1;
+
+            self.active_policies.insert(policy_id.clone(), (coverage_amount, policy_data.end_time));
+            self.active_exposure += coverage_amount;
+
+            let policy = self.policy_manager.mint_non_fungible(&policy_id, policy_data.clone());
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::POLICY_PURCHASED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::PolicyPurchased(policy_data),
+            });
+
+            if self.active_exposure >= self.capacity_limit() {
+                Runtime::emit_event(PandaoEvent {
+                    event_type: EventType::CAPACITY_REACHED,
+                    dao_type: DaoType::Insurance,
+                    component_address: Runtime::global_address(),
+                    meta_data: DaoEvent::CapacityReached(CapacityReached {
+                        pool_name: self.name.clone(),
+                        active_exposure: self.active_exposure,
+                        capacity_limit: self.capacity_limit(),
+                    }),
+                });
+            }
+
+            // delivered straight to the buyer, whose account is the recorded holder
+            account.try_deposit_or_abort(policy.into(), None);
+
+            payment
+        }
+
+        // applies the policy terms to a reported loss and pays from this pool's capital only;
+        // the DAO handles any shortfall
+        pub fn pay_claim(
+            &mut self,
+            policy_id: NonFungibleLocalId,
+            loss_amount: Decimal,
+            incident_category: String,
+            incident_time: i64,
+        ) -> (ComponentAddress, Bucket, PayoutBreakdown) {
+            self.assert_claimable(policy_id.clone(), incident_time);
+
+            let policy: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            assert!(
+                !policy.terms.excludes(&incident_category),
+                "this incident category is excluded from the policy"
+            );
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+            let policy_year = (now - policy.start_time) / SECONDS_IN_YEAR;
+
+            let claimed_in_year = if policy.aggregate_year == policy_year {
+                policy.claimed_in_year
+            } else {
+                Decimal::zero()
+            };
+
+            let breakdown = policy.terms.compute_payout(
+                loss_amount,
+                claimed_in_year,
+                policy.coverage_amount - policy.claimed_amount,
+            );
+
+            let payout = self.capital.take(breakdown.payout.min(self.capital.amount()));
+
+            self.policy_manager.update_non_fungible_data(
+                &policy_id,
+                "claimed_amount",
+                policy.claimed_amount + breakdown.payout,
+            );
+            self.policy_manager.update_non_fungible_data(&policy_id, "aggregate_year", policy_year);
+            self.policy_manager.update_non_fungible_data(
+                &policy_id,
+                "claimed_in_year",
+                claimed_in_year + breakdown.payout,
+            );
+
+            self.total_claims_paid += payout.amount();
+
+            (policy.beneficiary, payout, breakdown)
+        }
+
+        // extends the policy at a premium quoted for today's pool state; a renewal in the grace period
+        // starts now and leaves the time since expiry uncovered
+        pub fn renew_policy(&mut self, policy: Proof, mut payment: Bucket, duration_seconds: i64) -> Bucket {
+            assert!(duration_seconds > 0, "coverage duration must be positive");
+
+            let policy_id = self.checked_policy_id(policy);
+
+            assert!(
+                self.get_policy_status(policy_id.clone()) != PolicyStatus::Lapsed,
+                "this policy has lapsed and can no longer be renewed"
+            );
+
+            let policy_data: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            let premium = self
+                .quote_premium(policy_data.coverage_amount, duration_seconds, policy_data.risk_class)
+                .premium;
+
+            assert!(payment.amount() >= premium, "you are paying an insufficient premium");
+
+            let retained = self.share_premium(payment.take(premium));
+            self.capital.put(retained);
+            self.total_premiums += premium;
+
+            // the renewed policy keeps holding capacity, so it has to fit like a new one would
+            assert!(
+                self.current_exposure() <= self.capacity_limit(),
+                "this pool has no capacity left to renew the policy"
+            );
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let new_end_time = if now > policy_data.end_time {
+                let mut uncovered_gaps = policy_data.uncovered_gaps.clone();
+                uncovered_gaps.push((policy_data.end_time, now));
+
+                self.policy_manager.update_non_fungible_data(&policy_id, "uncovered_gaps", uncovered_gaps);
+
+                now + duration_seconds
+            } else {
+                policy_data.end_time + duration_seconds
+            };
+
+            self.policy_manager.update_non_fungible_data(&policy_id, "end_time", new_end_time);
+            self.policy_manager.update_non_fungible_data(
+                &policy_id,
+                "premium_paid",
+                policy_data.premium_paid + premium,
+            );
+
+            self.active_policies.insert(policy_id.clone(), (policy_data.coverage_amount, new_end_time));
+
+            let event_metadata = PolicyRenewed {
+                pool_name: self.name.clone(),
+                policy_id,
+                premium_paid: premium,
+                new_end_time,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::POLICY_RENEWED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::PolicyRenewed(event_metadata),
+            });
+
+            payment
+        }
+
+        pub fn designate_beneficiary(&mut self, policy: Proof, beneficiary: ComponentAddress) {
+            let policy_id = self.checked_policy_id(policy);
+
+            self.policy_manager.update_non_fungible_data(&policy_id, "beneficiary", beneficiary);
+
+            let policy_data: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            self.emit_ownership_event(EventType::BENEFICIARY_DESIGNATED, policy_id, &policy_data);
+        }
+
+        // mints the permit a DAO-approved transfer needs; it is handed to the current holder
+        pub fn approve_transfer(&mut self, policy_id: NonFungibleLocalId, new_holder: ComponentAddress) -> Bucket {
+            assert!(
+                self.transferability == PolicyTransferability::DaoApproved,
+                "policies of this pool are not transferred through DAO approval"
+            );
+
+            self.assert_not_superseded(&policy_id);
+
+            let permit_id = NonFungibleLocalId::integer(self.next_permit_id);
+            self.next_permit_id += 1;
+
+            self.transfer_permit_manager
+                .mint_non_fungible(&permit_id, PolicyTransferPermit { policy_id, new_holder })
+                .into()
+        }
+
+        // the holder proves the policy and hands in the permit; the old NFT is voided and a new one
+        // with the same cover is minted to the new holder
+        pub fn transfer_policy(&mut self, policy: Proof, permit: Bucket) {
+            assert!(
+                permit.resource_address() == self.transfer_permit_manager.address(),
+                "this is not a transfer permit of this pool"
+            );
+
+            let policy_id = self.checked_policy_id(policy);
+            let permit_id = permit.as_non_fungible().non_fungible_local_id();
+
+            let permit_data: PolicyTransferPermit = self.transfer_permit_manager.get_non_fungible_data(&permit_id);
+
+            assert!(permit_data.policy_id == policy_id, "this permit is for another policy");
+
+            permit.burn();
+
+            let old_policy: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            let new_policy_id = NonFungibleLocalId::integer(self.next_policy_id);
+            self.next_policy_id += 1;
+
+            let mut new_policy = old_policy.clone();
+            new_policy.holder = permit_data.new_holder;
+
+            // payouts follow the policy unless the old holder had named someone else
+            if old_policy.beneficiary == old_policy.holder {
+                new_policy.beneficiary = permit_data.new_holder;
+            }
+
+            self.policy_manager
+                .update_non_fungible_data(&policy_id, "superseded_by", Some(new_policy_id.clone()));
+
+            if let Some(entry) = self.active_policies.remove(&policy_id) {
+                self.active_policies.insert(new_policy_id.clone(), entry);
+            }
+
+            if let Some(observation) = self.parametric_settlements.remove(&policy_id) {
+                self.parametric_settlements.insert(new_policy_id.clone(), observation);
+            }
+
+            let reissued = self.policy_manager.mint_non_fungible(&new_policy_id, new_policy.clone());
+
+            let mut new_holder_account: Global<Account> = Global::from(permit_data.new_holder);
+            new_holder_account.try_deposit_or_abort(reissued.into(), None);
+
+            self.emit_ownership_event(EventType::POLICY_TRANSFERRED, new_policy_id, &new_policy);
+        }
+
+        // whoever holds a freely transferable policy registers as its holder before claiming
+        pub fn register_holder(&mut self, policy: Proof, account: Global<Account>) -> NonFungibleLocalId {
+            let owner_role_of_holder = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_holder.rule);
+
+            let policy_id = self.checked_policy_id(policy);
+
+            self.set_holder(policy_id.clone(), account.address());
+
+            policy_id
+        }
+
+        // the DAO has already checked the proof and the account
+        pub fn record_holder(&mut self, policy_id: NonFungibleLocalId, holder: ComponentAddress) {
+            self.set_holder(policy_id, holder);
+        }
+
+        fn set_holder(&mut self, policy_id: NonFungibleLocalId, holder: ComponentAddress) {
+            let policy_data: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            if policy_data.holder == holder {
+                return;
+            }
+
+            self.policy_manager.update_non_fungible_data(&policy_id, "holder", holder);
+
+            if policy_data.beneficiary == policy_data.holder {
+                self.policy_manager.update_non_fungible_data(&policy_id, "beneficiary", holder);
+            }
+
+            let policy_data: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            self.emit_ownership_event(EventType::POLICY_TRANSFERRED, policy_id, &policy_data);
+        }
+
+        fn checked_policy_id(&self, policy: Proof) -> NonFungibleLocalId {
+            let policy_id = policy
+                .check_with_message(self.policy_manager.address(), "this is not a policy of this pool")
+                .as_non_fungible()
+                .non_fungible_local_id();
+
+            self.assert_not_superseded(&policy_id);
+
+            policy_id
+        }
+
+        fn assert_not_superseded(&self, policy_id: &NonFungibleLocalId) {
+            let policy: CoveragePolicy = self.policy_manager.get_non_fungible_data(policy_id);
+
+            assert!(policy.superseded_by.is_none(), "this policy was reissued to a new holder");
+        }
+
+        fn emit_ownership_event(&self, event_type: EventType, policy_id: NonFungibleLocalId, policy: &CoveragePolicy) {
+            let event_metadata = PolicyOwnershipChanged {
+                pool_name: self.name.clone(),
+                policy_id,
+                holder: policy.holder,
+                beneficiary: policy.beneficiary,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::PolicyOwnershipChanged(event_metadata),
+            });
+        }
+
+        pub fn get_policy_status(&self, policy_id: NonFungibleLocalId) -> PolicyStatus {
+            let policy: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            if now < policy.end_time {
+                PolicyStatus::Active
+            } else if now < policy.end_time + self.grace_period_seconds {
+                PolicyStatus::GracePeriod
+            } else {
+                PolicyStatus::Lapsed
+            }
+        }
+
+        // frees the capacity held by policies whose grace period ran out; anyone may call it
+        pub fn process_lapses(&mut self) {
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let lapsed: Vec<NonFungibleLocalId> = self
+                .active_policies
+                .iter()
+                .filter(|(_, (_, end_time))| *end_time + self.grace_period_seconds <= now)
+                .map(|(policy_id, _)| policy_id.clone())
+                .collect();
+
+            for policy_id in lapsed {
+                let (coverage_amount, _) = self.active_policies.remove(&policy_id).unwrap();
+
+                self.active_exposure -= coverage_amount;
+
+                let policy: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+                let event_metadata = PolicyLapsed {
+                    pool_name: self.name.clone(),
+                    policy_id,
+                    holder: policy.holder,
+                    lapsed_at: now,
+                };
+
+                Runtime::emit_event(PandaoEvent {
+                    event_type: EventType::POLICY_LAPSED,
+                    dao_type: DaoType::Insurance,
+                    component_address: Runtime::global_address(),
+                    meta_data: DaoEvent::PolicyLapsed(event_metadata),
+                });
+            }
+        }
+
+        pub fn add_capital(&mut self, capital: Bucket) {
+            self.capital.put(capital);
+        }
+
+        // capital can only leave while what remains still backs the cover outstanding
+        pub fn withdraw_capital(&mut self, amount: Decimal) -> Bucket {
+            let capital = self.capital.take(amount);
+
+            assert!(
+                self.current_exposure() <= self.capital.amount() * self.max_exposure_multiple,
+                "withdrawing this much would leave the pool's exposure uncovered"
+            );
+
+            capital
+        }
+
+        pub fn update_parameters(&mut self, premium_rate: Decimal, max_coverage: Decimal, max_exposure_multiple: Decimal) {
+            self.premium_rate = premium_rate;
+            self.max_coverage = max_coverage;
+            self.max_exposure_multiple = max_exposure_multiple;
+        }
+
+        pub fn get_pool_info(&self) -> RiskPoolInfo {
+            RiskPoolInfo {
+                name: self.name.clone(),
+                capital: self.capital.amount(),
+                premium_rate: self.premium_rate,
+                max_coverage: self.max_coverage,
+                max_exposure_multiple: self.max_exposure_multiple,
+                active_exposure: self.current_exposure(),
+                utilization: self.get_utilization(),
+                parametric_trigger: self.parametric_trigger.clone(),
+                transferability: self.transferability,
+                terms: self.terms.clone(),
+                underwriting: self.underwriting.clone(),
+                total_premiums: self.total_premiums,
+                total_claims_paid: self.total_claims_paid,
+                policy_resource: self.policy_manager.address(),
+            }
+        }
+
+        // whether the oracle reported a triggering value while the policy was in force
+        pub fn parametric_condition_met(&self, policy_id: NonFungibleLocalId) -> bool {
+            self.parametric_trigger_observation(policy_id).is_some()
+        }
+
+        // marks a parametric policy as paid so a later reading, top-up or renewal can't pay it again
+        pub fn record_parametric_settlement(&mut self, policy_id: NonFungibleLocalId) -> Observation {
+            assert!(
+                !self.parametric_settlements.contains_key(&policy_id),
+                "this parametric policy has already been settled"
+            );
+
+            let observation = self
+                .parametric_trigger_observation(policy_id.clone())
+                .expect("the parametric condition did not hold during the coverage period");
+
+            self.parametric_settlements.insert(policy_id, observation.clone());
+
+            observation
+        }
+
+        // first triggering observation the policy covers, past the waiting period and outside any renewal gap
+        pub fn parametric_trigger_observation(&self, policy_id: NonFungibleLocalId) -> Option<Observation> {
+            let trigger = match &self.parametric_trigger {
+                Some(trigger) => trigger,
+                None => return None,
+            };
+
+            let policy: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            let oracle: Global<AnyComponent> = Global::from(trigger.oracle);
+            let observations: Vec<Observation> = oracle.call_raw(
+                "get_observations_between",
+                scrypto_args!(policy.start_time + self.underwriting.waiting_period_seconds, policy.end_time),
+            );
+
+            observations
+                .iter()
+                .find(|observation| {
+                    trigger.is_triggered_by(observation.value)
+                        && policy.covers(observation.observed_at, self.underwriting.waiting_period_seconds)
+                })
+                .cloned()
+        }
+
+        // incidents in the waiting period, a renewal gap or outside the cover are rejected outright
+        pub fn assert_claimable(&self, policy_id: NonFungibleLocalId, incident_time: i64) {
+            let policy: CoveragePolicy = self.policy_manager.get_non_fungible_data(&policy_id);
+
+            assert!(policy.superseded_by.is_none(), "this policy was reissued to a new holder");
+
+            assert!(
+                policy.covers(incident_time, self.underwriting.waiting_period_seconds),
+                "the incident happened outside the covered period"
+            );
+        }
+
+        pub fn get_policy(&self, policy_id: NonFungibleLocalId) -> CoveragePolicy {
+            self.policy_manager.get_non_fungible_data(&policy_id)
+        }
+
+        // share of the pool's capacity taken by policies that have not expired yet
+        pub fn get_utilization(&self) -> Decimal {
+            let capacity_limit = self.capacity_limit();
+
+            if capacity_limit == Decimal::zero() {
+                return Decimal::one();
+            }
+
+            self.current_exposure() / capacity_limit
+        }
+
+        fn record_account_cover(&mut self, account: ComponentAddress, coverage_amount: Decimal) {
+            let window = Runtime::current_epoch().number() / self.underwriting.cover_window_epochs.max(1);
+
+            let bought_in_window = match self.account_cover.get(&account) {
+                Some((account_window, bought)) if *account_window == window => *bought,
+                _ => Decimal::zero(),
+            };
+
+            assert!(
+                bought_in_window + coverage_amount <= self.underwriting.max_cover_per_account,
+                "this account has reached its cover limit for the current window"
+            );
+
+            self.account_cover.insert(account, (window, bought_in_window + coverage_amount));
+        }
+
+        // stakers get the same cut of pool premiums as of premiums paid to the DAO, the rest stays here
+        fn share_premium(&self, premium: Bucket) -> Bucket {
+            let dao: Global<AnyComponent> = Global::from(self.dao_address);
+
+            dao.call_raw("share_pool_premium", scrypto_args!(self.name.clone(), premium))
+        }
+
+        // the lower of the hard coverage cap and what the capital can back
+        fn capacity_limit(&self) -> Decimal {
+            self.max_coverage.min(self.capital.amount() * self.max_exposure_multiple)
+        }
+
+        // policies in their grace period keep their capacity so they can be renewed
+        fn current_exposure(&self) -> Decimal {
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            self.active_policies
+                .values()
+                .filter(|(_, end_time)| *end_time + self.grace_period_seconds > now)
+                .fold(Decimal::zero(), |total, (coverage_amount, _)| total + *coverage_amount)
+        }
+
+        pub fn quote_premium(&self, coverage_amount: Decimal, duration_seconds: i64, risk_class: RiskClass) -> PremiumQuote {
+            crate::pricing::quote_premium(
+                self.premium_rate,
+                coverage_amount,
+                duration_seconds,
+                risk_class,
+                self.get_utilization(),
+                loss_ratio(self.total_claims_paid, self.total_premiums),
+            )
+        }
+    }
+}
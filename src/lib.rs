@@ -1,10 +1,44 @@
 // blue print for
-mod events;
+pub mod events;
 use crate::events::*;
-mod proposal;
+pub mod proposal;
 use scrypto::prelude::*;
+use scrypto::engine::scrypto_env::ScryptoVmV1Api;
 
-mod ann;
+pub mod ann;
+
+pub mod rate_oracle;
+
+pub mod swap_pool;
+
+pub mod insurance;
+use crate::insurance::*;
+pub mod pricing;
+use crate::pricing::*;
+pub mod oracle;
+use crate::oracle::*;
+pub mod assessors;
+use crate::assessors::*;
+pub mod reinsurance;
+use crate::reinsurance::*;
+
+pub mod bonding_curve;
+use crate::bonding_curve::*;
+
+pub mod vesting;
+use crate::vesting::*;
+
+pub mod streaming;
+use crate::streaming::*;
+
+pub mod budget;
+use crate::budget::*;
+
+pub mod membership;
+use crate::membership::*;
+
+pub mod staking;
+use crate::staking::*;
 
 #[blueprint]
 #[events(PandaoEvent, DaoEvent, TokenWightedDeployment, DaoType, EventType)]
@@ -17,10 +51,16 @@ mod radixdao {
     use scrypto::address;
     // use scrypto_test::prelude::drop_fungible_bucket;
     use ann::annuity::Annuity;
+    use ann::RateResetTerms;
+    use swap_pool::swap_pool::SwapPool;
+    use insurance::insurance_pool::InsurancePool;
 
     pub struct TokenWeigtedDao {
         current_praposals: HashMap<ComponentAddress, HashMap<usize, Global<TokenWeightProposal>>>,
 
+        // ids are handed out in order so proposals created in the same epoch never collide
+        next_proposal_id: usize,
+
         dao_token_resource_manager: ResourceManager,
 
         dao_token: Vault,
@@ -41,7 +81,157 @@ mod radixdao {
 
         ann_token: HashMap<ComponentAddress, Vec<Global<Annuity>>>, 
 
-        contributors: HashMap<ComponentAddress, Decimal>,
+        contributors: HashMap<ComponentAddress, ContributorRecord>,
+
+        // running totals of everything that entered or left the treasury, per resource
+        treasury_inflows: HashMap<ResourceAddress, Decimal>,
+
+        treasury_outflows: HashMap<ResourceAddress, Decimal>,
+
+        // vaults for whitelisted fungibles other than XRD, which stays in `shares`
+        treasury: KeyValueStore<ResourceAddress, Vault>,
+
+        whitelisted_assets: HashSet<ResourceAddress>,
+
+        // swap pools governance approved for treasury rebalancing
+        swap_pools: HashSet<ComponentAddress>,
+
+        // asset insurance premiums are collected in and claims are paid out in
+        stable_asset: ResourceAddress,
+
+        premiums_collected: Decimal,
+
+        // when set, tokens are priced along the curve instead of `token_price` / `buy_back_price`
+        bonding_curve: Option<BondingCurve>,
+
+        // tokens sold along the curve and not yet bought back
+        curve_supply: Decimal,
+
+        // XRD set aside from curve purchases to fund buy-backs
+        curve_reserve: Vault,
+
+        // fraction of `shares` locked as claim reserve
+        claim_reserve_ratio: Decimal,
+
+        // fraction of in-force cover held back for incurred but not yet reported claims
+        ibnr_ratio: Decimal,
+
+        // claimed amounts of open claim proposals, kept as running totals for the case reserve
+        open_claims: Decimal,
+        // open policy claims and claim reviews per risk pool
+        open_policy_claims: HashMap<String, Decimal>,
+
+        // buy-backs the free balance could not cover yet, oldest first
+        redemption_queue: Vec<RedemptionRequest>,
+
+        next_redemption_id: u64,
+
+        // settled redemptions waiting to be collected, still held in the treasury
+        claimable_redemptions: HashMap<ComponentAddress, HashMap<ResourceAddress, Decimal>>,
+
+        total_claimable_redemptions: HashMap<ResourceAddress, Decimal>,
+
+        // governance tokens minted for beneficiaries that have not been claimed yet
+        vesting_vault: Vault,
+
+        vesting_receipt_manager: NonFungibleResourceManager,
+
+        next_vesting_id: u64,
+
+        payment_streams: HashMap<u64, PaymentStream>,
+
+        // funds escrowed from the treasury for each stream until they are withdrawn or cancelled
+        stream_vaults: KeyValueStore<u64, Vault>,
+
+        next_stream_id: u64,
+
+        // spending caps per category and asset, pairs without an entry are unconstrained
+        budgets: HashMap<(BudgetCategory, ResourceAddress), BudgetLimit>,
+
+        member_badge_manager: NonFungibleResourceManager,
+
+        member_badges: HashMap<ComponentAddress, NonFungibleLocalId>,
+
+        // rewards distributed to badge holders and not yet claimed, per badge and resource
+        member_rewards: HashMap<NonFungibleLocalId, HashMap<ResourceAddress, Decimal>>,
+
+        member_reward_vaults: KeyValueStore<ResourceAddress, Vault>,
+
+        staking_vault: Vault,
+
+        stakes: HashMap<ComponentAddress, StakePosition>,
+
+        total_staked: Decimal,
+
+        // rewards credited per staked token, per reward resource
+        reward_per_token: HashMap<ResourceAddress, Decimal>,
+
+        staking_reward_vaults: KeyValueStore<ResourceAddress, Vault>,
+
+        // fraction of premiums and annuity payouts paid to stakers
+        staking_reward_share: Decimal,
+
+        staking_cooldown_seconds: i64,
+
+        // slashed tokens waiting in the sale vault, as (redemption request id, tokens left to sell)
+        slashed_token_sales: Vec<(u64, Decimal)>,
+
+        // independent lines of cover, each holding its own capital
+        risk_pools: HashMap<String, Global<InsurancePool>>,
+
+        // open appeals against rejected claims, keyed by the appeal proposal id
+        claim_disputes: HashMap<usize, ClaimDispute>,
+
+        // bonds posted by claimants while their appeal is open
+        dispute_bonds: KeyValueStore<ResourceAddress, Vault>,
+
+        // bond as a fraction of the disputed claim
+        dispute_bond_ratio: Decimal,
+
+        // appeals need this many times the quorum of the rejected proposal
+        appeal_quorum_multiplier: u8,
+
+        appeal_voting_period_seconds: i64,
+
+        // governance tokens staked by claim assessors
+        assessor_vault: Vault,
+
+        assessor_stakes: HashMap<ComponentAddress, Decimal>,
+
+        // registration order, committees are drawn from it
+        assessor_list: Vec<ComponentAddress>,
+
+        // when each listed assessor joined, only those listed before a policy started review its claims
+        assessor_registered_at: HashMap<ComponentAddress, i64>,
+
+        claim_reviews: HashMap<u64, ClaimReview>,
+
+        next_review_id: u64,
+
+        // review fees paid by claimants, held until their review is finalized
+        review_fee_vaults: KeyValueStore<ResourceAddress, Vault>,
+
+        assessor_committee_size: usize,
+
+        review_window_seconds: i64,
+
+        review_fee: Decimal,
+
+        // fraction of stake taken from assessors who voted against the majority
+        assessor_slash_ratio: Decimal,
+
+        minimum_assessor_stake: Decimal,
+
+        // every claim amount this DAO has settled or queued, the basis for reinsurance recoveries
+        aggregate_claims_paid: Decimal,
+
+        // treaties this DAO wrote as reinsurer
+        written_treaties: HashMap<u64, ReinsuranceTreaty>,
+
+        next_treaty_id: u64,
+
+        // treaties this DAO bought, as (reinsurer, treaty id)
+        reinsurance_cover: Vec<(ComponentAddress, u64)>,
 
         proposal_creation_right: ProposalCreationRight,
     }
@@ -70,11 +260,17 @@ mod radixdao {
 
             proposal_creation_right: ProposalCreationRight,
 
-            token_name : String
+            token_name : String,
+
+            bonding_curve: Option<BondingCurve>,
 
         ) -> (Global<TokenWeigtedDao>, Bucket) {
+            if let Some(curve) = &bonding_curve {
+                curve.assert_valid();
+            }
+
             // reserve an address for the DAO component
-            let (address_reservation, _) =
+            let (address_reservation, component_address) =
                 Runtime::allocate_component_address(TokenWeigtedDao::blueprint_id());
 
             let owner_badge_description = format!("{}'s owner badge", &organization_name);
@@ -100,249 +296,237 @@ mod radixdao {
                     "organization name" => organization_name.as_str() , locked ;
                     "icon_url" => Url::of(&power_token_url), locked;
                 }))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
                 .mint_initial_supply(token_supply)
                 .into();
 
+            let vesting_receipt_manager = ResourceBuilder::new_integer_non_fungible::<VestingReceipt>(OwnerRole::None)
+                .metadata(metadata!(init{
+                    "name" => format!("{} vesting receipt", &token_name), locked;
+                }))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
+                .non_fungible_data_update_roles(non_fungible_data_update_roles!(
+                    non_fungible_data_updater => rule!(require(global_caller(component_address)));
+                    non_fungible_data_updater_updater => rule!(deny_all);
+                ))
+                .create_with_no_initial_supply();
+
+            let member_badge_manager = ResourceBuilder::new_integer_non_fungible::<MemberBadge>(OwnerRole::None)
+                .metadata(metadata!(init{
+                    "name" => format!("{} membership badge", &organization_name), locked;
+                    "icon_url" => Url::of(&org_ico_url), locked;
+                }))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
+                .non_fungible_data_update_roles(non_fungible_data_update_roles!(
+                    non_fungible_data_updater => rule!(require(global_caller(component_address)));
+                    non_fungible_data_updater_updater => rule!(deny_all);
+                ))
+                .create_with_no_initial_supply();
+
             let dao_token_address = voting_power_tokens.resource_address();
 
             let owner_token_addresss = owner_badge.resource_address();
 
-            let component: Global<TokenWeigtedDao>;
+            let component: Global<TokenWeigtedDao> = Self {
+                token_price: token_price.clone(),
 
+                organization_name: organization_name.clone(),
 
+                dao_token_address: dao_token_address.clone(),
 
-            match proposal_creation_right {
-                ProposalCreationRight::EVERYONE => {
-                    component = Self {
-                        token_price: token_price.clone(),
-
-                        organization_name: organization_name.clone(),
+                owner_token_addresss: owner_token_addresss.clone(),
 
-                        dao_token_address: dao_token_address.clone(),
+                current_praposals: HashMap::new(),
 
-                        owner_token_addresss: owner_token_addresss.clone(),
+                next_proposal_id: 0,
 
-                        current_praposals: HashMap::new(),
+                dao_token_resource_manager: voting_power_tokens.resource_manager(),
 
-                        dao_token_resource_manager: voting_power_tokens.resource_manager(),
+                dao_token: Vault::with_bucket(voting_power_tokens),
 
-                        dao_token: Vault::with_bucket(voting_power_tokens),
+                buy_back_price: token_buy_back_price.clone(),
 
-                        buy_back_price: token_buy_back_price.clone(),
+                shares: Vault::new(XRD),
 
-                        shares: Vault::new(XRD),
+                ann_tokens: HashMap::new(),
 
-                        ann_tokens: HashMap::new(),
+                // Initialize zero_coupon_bond as None
+                ann_token: HashMap::new(),
 
-                        // Initialize zero_coupon_bond as None
-                        ann_token: HashMap::new(),
+                contributors: HashMap::new(),
 
-                        contributors: HashMap::new(),
+                treasury_inflows: HashMap::new(),
 
-                        proposal_creation_right: ProposalCreationRight::EVERYONE,
-                    }
-                    .instantiate()
-                    .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
-                        owner_token_addresss.clone()
-                    ))))
-                    .with_address(address_reservation.clone())
-                    .globalize();
+                treasury_outflows: HashMap::new(),
 
-                    let component_address = component.address();
+                treasury: KeyValueStore::new(),
 
-                    let event_metadata = TokenWightedDeployment {
-                        component_address,
+                whitelisted_assets: HashSet::from([XRD]),
 
-                        token_address: dao_token_address,
+                swap_pools: HashSet::new(),
 
-                        owner_token_address: owner_token_addresss,
+                stable_asset: XRD,
 
-                        community_name: organization_name,
+                premiums_collected: Decimal::zero(),
 
-                        community_image: org_ico_url,
+                bonding_curve: bonding_curve.clone(),
 
-                        token_price,
+                curve_supply: Decimal::zero(),
 
-                        token_buy_back_price,
+                curve_reserve: Vault::new(XRD),
 
-                        description,
+                claim_reserve_ratio: Decimal::zero(),
 
-                        total_token: token_supply,
+                ibnr_ratio: Decimal::zero(),
 
-                        token_image: power_token_url,
+                open_claims: Decimal::zero(),
 
-                        tags: tags.clone(),
+                open_policy_claims: HashMap::new(),
 
-                        purpose: purpose.clone(),
+                redemption_queue: Vec::new(),
 
-                        proposal_creation_right: ProposalCreationRight::EVERYONE,
-                    };
+                next_redemption_id: 0,
 
-                    Runtime::emit_event(PandaoEvent {
-                        // event_type: EventType::PROPOSAL_CREATION_RIGHT,
-                        event_type: EventType::DEPLOYMENT,
-                        dao_type: DaoType::Insurance,
-                        component_address,
-                        meta_data: DaoEvent::TokenWeightedDEployment(event_metadata),
-                    });
-                
+                claimable_redemptions: HashMap::new(),
 
-                }
-                ProposalCreationRight::TOKEN_HOLDER_THRESHOLD(threshold) => {
-                    component = Self {
-                        token_price: token_price.clone(),
+                total_claimable_redemptions: HashMap::new(),
 
-                        organization_name: organization_name.clone(),
+                vesting_vault: Vault::new(dao_token_address),
 
-                        dao_token_address: dao_token_address.clone(),
+                vesting_receipt_manager,
 
-                        owner_token_addresss: owner_token_addresss.clone(),
+                next_vesting_id: 0,
 
-                        current_praposals: HashMap::new(),
+                payment_streams: HashMap::new(),
 
-                        dao_token_resource_manager: voting_power_tokens.resource_manager(),
+                stream_vaults: KeyValueStore::new(),
 
-                        dao_token: Vault::with_bucket(voting_power_tokens),
+                next_stream_id: 0,
 
-                        buy_back_price: token_buy_back_price.clone(),
+                budgets: HashMap::new(),
 
-                        shares: Vault::new(XRD),
+                member_badge_manager,
 
-                        ann_tokens: HashMap::new(),
+                member_badges: HashMap::new(),
 
-                        // Initialize zero_coupon_bond as None
-                        ann_token: HashMap::new(),
+                member_rewards: HashMap::new(),
 
-                        contributors: HashMap::new(),
+                member_reward_vaults: KeyValueStore::new(),
 
-                        proposal_creation_right: ProposalCreationRight::TOKEN_HOLDER_THRESHOLD(
-                            threshold,
-                        ),
-                    }
-                    .instantiate()
-                    .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
-                        owner_token_addresss.clone()
-                    ))))
-                    .with_address(address_reservation.clone())
-                    .globalize();
+                staking_vault: Vault::new(dao_token_address),
 
-                    let component_address = component.address();
+                stakes: HashMap::new(),
 
-                    let event_metadata = TokenWightedDeployment {
-                        component_address,
+                total_staked: Decimal::zero(),
 
-                        token_address: dao_token_address,
+                reward_per_token: HashMap::new(),
 
-                        owner_token_address: owner_token_addresss,
+                staking_reward_vaults: KeyValueStore::new(),
 
-                        community_name: organization_name,
+                staking_reward_share: dec!("0.2"),
 
-                        community_image: org_ico_url,
+                staking_cooldown_seconds: 7 * 24 * 60 * 60,
 
-                        token_price,
+                slashed_token_sales: Vec::new(),
 
-                        token_buy_back_price,
+                risk_pools: HashMap::new(),
 
-                        description,
+                claim_disputes: HashMap::new(),
 
-                        total_token: token_supply,
+                dispute_bonds: KeyValueStore::new(),
 
-                        token_image: power_token_url,
+                dispute_bond_ratio: dec!("0.1"),
 
-                        tags: tags.clone(),
+                appeal_quorum_multiplier: 2,
 
-                        purpose: purpose.clone(),
+                appeal_voting_period_seconds: 14 * 24 * 60 * 60,
 
-                        proposal_creation_right: ProposalCreationRight::TOKEN_HOLDER_THRESHOLD(
-                            threshold,
-                        ),
-                    };
+                assessor_vault: Vault::new(dao_token_address),
 
-                    Runtime::emit_event(PandaoEvent {
-                        // event_type: EventType::PROPOSAL_CREATION_RIGHT,
-                        event_type: EventType::DEPLOYMENT,
-                        dao_type: DaoType::Insurance,
-                        component_address,
-                        meta_data: DaoEvent::TokenWeightedDEployment(event_metadata),
-                    });
+                assessor_stakes: HashMap::new(),
 
-                }
-                ProposalCreationRight::ADMIN => {
-                    component = Self {
-                        token_price: token_price.clone(),
+                assessor_list: Vec::new(),
 
-                        organization_name: organization_name.clone(),
+                assessor_registered_at: HashMap::new(),
 
-                        dao_token_address: dao_token_address.clone(),
+                claim_reviews: HashMap::new(),
 
-                        owner_token_addresss: owner_token_addresss.clone(),
+                next_review_id: 0,
 
-                        current_praposals: HashMap::new(),
+                review_fee_vaults: KeyValueStore::new(),
 
-                        dao_token_resource_manager: voting_power_tokens.resource_manager(),
+                assessor_committee_size: 5,
 
-                        dao_token: Vault::with_bucket(voting_power_tokens),
+                review_window_seconds: 3 * 24 * 60 * 60,
 
-                        buy_back_price: token_buy_back_price.clone(),
+                review_fee: dec!("10"),
 
-                        shares: Vault::new(XRD),
+                assessor_slash_ratio: dec!("0.1"),
 
-                        ann_tokens: HashMap::new(),
+                minimum_assessor_stake: dec!("100"),
 
-                        // Initialize zero_coupon_bond as None
-                        ann_token: HashMap::new(),
+                aggregate_claims_paid: Decimal::zero(),
 
-                        contributors: HashMap::new(),
+                written_treaties: HashMap::new(),
 
-                        proposal_creation_right: ProposalCreationRight::ADMIN,
-                    }
-                    .instantiate()
-                    .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
-                        owner_token_addresss.clone()
-                    ))))
-                    .with_address(address_reservation.clone())
-                    .globalize();
+                next_treaty_id: 0,
 
-                    let component_address = component.address();
+                reinsurance_cover: Vec::new(),
 
-                    let event_metadata = TokenWightedDeployment {
-                        component_address,
+                proposal_creation_right: proposal_creation_right.clone(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
+                owner_token_addresss.clone()
+            ))))
+            .with_address(address_reservation.clone())
+            .globalize();
+
+            let event_metadata = TokenWightedDeployment {
+                component_address,
 
-                        token_address: dao_token_address,
+                token_address: dao_token_address,
 
-                        owner_token_address: owner_token_addresss,
+                owner_token_address: owner_token_addresss,
 
-                        community_name: organization_name,
+                community_name: organization_name,
 
-                        community_image: org_ico_url,
+                community_image: org_ico_url,
 
-                        token_price,
+                token_price,
 
-                        token_buy_back_price,
+                token_buy_back_price,
 
-                        description,
+                description,
 
-                        total_token: token_supply,
+                total_token: token_supply,
 
-                        token_image: power_token_url,
+                token_image: power_token_url,
 
-                        tags: tags.clone(),
+                tags: tags.clone(),
 
-                        purpose: purpose.clone(),
+                purpose: purpose.clone(),
 
-                        proposal_creation_right: ProposalCreationRight::ADMIN,
-                    };
+                proposal_creation_right,
 
-                    Runtime::emit_event(PandaoEvent {
-                        // event_type: EventType::PROPOSAL_CREATION_RIGHT,
-                        event_type: EventType::DEPLOYMENT,
-                        dao_type: DaoType::Insurance,
-                        component_address,
-                        meta_data: DaoEvent::TokenWeightedDEployment(event_metadata),
-                    });
+                bonding_curve,
+            };
 
-                }
-            }
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::DEPLOYMENT,
+                dao_type: DaoType::Insurance,
+                component_address,
+                meta_data: DaoEvent::TokenWeightedDEployment(event_metadata),
+            });
 
             (component, owner_badge)
         }
@@ -353,19 +537,34 @@ mod radixdao {
             token_amount: Decimal,
             // minter_address: Option<String>,
         ) -> (Bucket, Bucket) {
+            let amount_paid = self.get_purchase_cost(token_amount);
+
             assert!(
-                (self.token_price * token_amount) <= xrd.amount(),
+                amount_paid <= xrd.amount(),
                 "you are paying an insufficient amount"
             );
 
-            let collected_xrd = xrd.take(self.token_price * token_amount);
+            let mut collected_xrd = xrd.take(amount_paid);
 
             let power_share = self.dao_token.take(token_amount);
 
-            let amount_paid = self.token_price * token_amount;
+            if let Some(curve) = &self.bonding_curve {
+                self.curve_reserve.put(collected_xrd.take(amount_paid * curve.reserve_ratio));
+                self.curve_supply += token_amount;
+            }
+
+            let treasury_share = collected_xrd.amount();
 
             self.shares.put(collected_xrd);
 
+            self.record_treasury_inflow(XRD, treasury_share);
+
+            if self.bonding_curve.is_none() {
+                self.settle_slashed_sales(token_amount, self.token_price);
+            }
+
+            self.process_redemption_queue();
+
             //emit event
 
             let event_metadata = TokenWeightBuyToken {
@@ -393,23 +592,49 @@ mod radixdao {
             (xrd, power_share)
         }
 
-        pub fn withdraw_power(&mut self, voting_power: Bucket) -> Bucket {
+        pub fn withdraw_power(&mut self, voting_power: Bucket, account: Global<Account>) -> Bucket {
             // put the voting power back
-            assert!(
-                !self.current_praposals.is_empty(),
-                "token can not be sold when there are active praposals or incomplete proposals"
-            );
-
             let power_amount = voting_power.amount();
 
             self.dao_token.put(voting_power);
 
+            let payout = match &self.bonding_curve {
+                Some(curve) => {
+                    // the reserve is path independent, the min only guards against rounding dust
+                    let sale_value = curve
+                        .sale_value(self.curve_supply, power_amount)
+                        .min(self.curve_reserve.amount());
+
+                    self.curve_supply -= power_amount;
+
+                    self.curve_reserve.take(sale_value)
+                }
+                None => {
+                    let amount_owed = power_amount * self.buy_back_price;
+
+                    self.process_redemption_queue();
+
+                    // earlier redemptions are served first, anything the free balance can't cover waits in line
+                    let pay_now = if self.has_queued_redemptions(XRD) {
+                        Decimal::zero()
+                    } else {
+                        amount_owed.min(self.get_free_balance(XRD))
+                    };
+
+                    if amount_owed > pay_now {
+                        self.enqueue_redemption(account.address(), XRD, amount_owed - pay_now);
+                    }
+
+                    self.withdraw_from_treasury(XRD, pay_now)
+                }
+            };
+
             let event_metadata = TokenWeightBuyToken {
                 amount: power_amount,
 
                 resource_address: self.dao_token_address.clone(),
 
-                amount_paid: power_amount * self.buy_back_price,
+                amount_paid: payout.amount(),
 
                 current_component_share: self.shares.amount(),
             };
@@ -423,140 +648,426 @@ mod radixdao {
                 meta_data: DaoEvent::TokenWeightedTokenPurchase(event_metadata),
             });
 
-            self.shares.take(power_amount * self.buy_back_price)
+            payout
         }
 
-        pub fn get_usd_price() -> Decimal {
-            let usd_price = Runtime::get_usd_price();
-            usd_price
+        // XRD needed to buy `token_amount` community tokens right now
+        pub fn get_purchase_cost(&self, token_amount: Decimal) -> Decimal {
+            match &self.bonding_curve {
+                Some(curve) => curve.purchase_cost(self.curve_supply, token_amount),
+                None => self.token_price * token_amount,
+            }
         }
 
-        //get_unique_random_number
-        pub fn get_proposal_id() -> u64 {
-            let current_epoch = Runtime::current_epoch();
-            let unique_number: u64 = current_epoch.number();
-            unique_number
+        // XRD paid out for selling `token_amount` community tokens back right now
+        pub fn get_buy_back_value(&self, token_amount: Decimal) -> Decimal {
+            match &self.bonding_curve {
+                Some(curve) => curve
+                    .sale_value(self.curve_supply, token_amount)
+                    .min(self.curve_reserve.amount()),
+                None => self.buy_back_price * token_amount,
+            }
         }
 
-        pub fn create_praposal(
-            &mut self,
-            title: String,
-            description: String,
-            minimun_quorum: u8,
-            start_time: scrypto::time::UtcDateTime,
-            end_time: scrypto::time::UtcDateTime,
-            address_issued_bonds_to_sell: Option<ComponentAddress>, // Address issued ANN token to sell
-            target_xrd_amount: Option<Decimal>,
-            proposal_creator_address: Option<ComponentAddress>,
-            governance_token_or_owner_token_address: Bucket,
-            voting_type: VotingType,
-        ) -> (
-            Global<crate::proposal::pandao_praposal::TokenWeightProposal>,
-            String,
-            Bucket,
-        ) {
-            //implement proposal creation rights
-            match self.proposal_creation_right {
-                ProposalCreationRight::EVERYONE => {
-                    assert_eq!(
-                        governance_token_or_owner_token_address.resource_address(),
-                        self.dao_token_address,
-                        "wrong voting token supplied! please make sure that you supply DAO Governance Token"
-                    );
+        pub fn get_current_token_price(&self) -> Decimal {
+            match &self.bonding_curve {
+                Some(curve) => curve.spot_price(self.curve_supply),
+                None => self.token_price,
+            }
+        }
 
-                    assert!(
-                        governance_token_or_owner_token_address.amount() >= Decimal::one(),
-                        "Proposal creator must have at least one governance token to create a proposal"
-                    );
+        pub fn get_curve_reserve(&self) -> Decimal {
+            self.curve_reserve.amount()
+        }
 
-                    //allow proposal creation
-                }
-                ProposalCreationRight::TOKEN_HOLDER_THRESHOLD(threshold) => {
-                    assert_eq!(
-                        governance_token_or_owner_token_address.resource_address(),
-                        self.dao_token_address,
-                        "wrong voting token supplied! please make sure that you supply DAO Governance Token"
-                    );
+        // part of `shares` that buy-backs may never touch because it backs insurance claims
+        pub fn get_claim_reserve(&self) -> Decimal {
+            self.shares.amount() * self.claim_reserve_ratio
+        }
 
-                    assert!(
-                        governance_token_or_owner_token_address.amount() >= threshold,
-                        "Proposal creator does not have enough tokens to meet the threshold"
-                    );
-                }
-                ProposalCreationRight::ADMIN => {
-                    assert_eq!(
-                        governance_token_or_owner_token_address.resource_address(),
-                        self.owner_token_addresss,
-                        "Only the admin can create a proposal and If you are an Admin please make sure you pass OWNER TOKEN ADDRESS"
-                    );
+        // claims still open or approved but unpaid; policy claims only count beyond their pool's capital
+        pub fn get_case_reserves(&self) -> Decimal {
+            self.open_policy_claims
+                .iter()
+                .fold(self.open_claims, |reserves, (pool_name, claimed)| {
+                    let capital = self.get_risk_pool(pool_name).get_pool_info().capital;
+
+                    if *claimed > capital {
+                        reserves + *claimed - capital
+                    } else {
+                        reserves
+                    }
+                })
+        }
 
-                    assert!(
-                        governance_token_or_owner_token_address.amount() >= Decimal::one(),
-                        "ADMIN must pass his/her OWNER TOKEN to create proposal"
-                    );
+        // keeps the running case reserve totals in step as claims open and close
+        fn track_open_claim(&mut self, action: &ProposalAction, opened: bool) {
+            match action {
+                ProposalAction::PayClaim { amount, .. } => {
+                    if opened {
+                        self.open_claims += *amount;
+                    } else {
+                        self.open_claims -= *amount;
+                    }
                 }
+                ProposalAction::PayPolicyClaim { pool_name, amount, .. } => {
+                    self.track_open_policy_claim(pool_name, *amount, opened);
+                }
+                _ => {}
             }
+        }
 
-            use crate::proposal::pandao_praposal::TokenWeightProposal;
+        fn track_open_policy_claim(&mut self, pool_name: &String, amount: Decimal, opened: bool) {
+            let claimed = self.open_policy_claims.entry(pool_name.clone()).or_insert(Decimal::zero());
 
-            if let Some(address_selling_ann_tokens) = address_issued_bonds_to_sell {
-                assert!(
-                    self.ann_token.contains_key(&address_selling_ann_tokens),
-                    "The Address you have specified has not created any ANN Token"
-                );
+            if opened {
+                *claimed += amount;
+            } else {
+                *claimed -= amount;
             }
 
-            let amount_of_tokens_should_be_minted = None;
-
-            let global_proposal_component: Global<TokenWeightProposal>;
-
-            match voting_type {
-                VotingType::ResourceHold => {
-                    (global_proposal_component, _) = TokenWeightProposal::new(
-                        title.clone(),
-                        description.clone(),
-                        minimun_quorum,
-                        start_time,
-                        end_time,
-                        self.owner_token_addresss.clone(),
-                        self.dao_token_address.clone(),
-                        address_issued_bonds_to_sell.clone(),
-                        target_xrd_amount.clone(),
-                        proposal_creator_address,
-                        amount_of_tokens_should_be_minted,
-                        VotingType::ResourceHold,
-                    );
-                }
-                VotingType::Equality => {
-                    (global_proposal_component, _) = TokenWeightProposal::new(
-                        title.clone(),
-                        description.clone(),
-                        minimun_quorum,
-                        start_time,
-                        end_time,
-                        self.owner_token_addresss.clone(),
-                        self.dao_token_address.clone(),
-                        address_issued_bonds_to_sell.clone(),
-                        target_xrd_amount.clone(),
-                        proposal_creator_address,
-                        amount_of_tokens_should_be_minted,
-                        VotingType::Equality,
-                    );
-                }
+            if *claimed <= Decimal::zero() {
+                self.open_policy_claims.remove(pool_name);
             }
+        }
 
-            let start_time_ts: i64 = start_time.to_instant().seconds_since_unix_epoch;
-            let end_time_ts: i64 = end_time.to_instant().seconds_since_unix_epoch;
+        // a rejected claim stays reserved until anyone closes it, once the dispute window after the vote passed
+        pub fn close_rejected_claim(&mut self, proposal_id: usize) {
+            let proposal = self
+                .find_proposal(proposal_id)
+                .expect("there is no current active proposal with the given ID");
 
-            //unique-id-generation
-            let proposal_id: usize = Self::get_proposal_id()
-                .try_into()
-                .expect("couldn't get called successfully");
-            //populate HashMap with newly created proposal
+            let action = proposal
+                .get_action()
+                .expect("this proposal does not carry a treasury action");
 
-            let inner_map = self
-                .current_praposals
-                .entry(proposal_creator_address.unwrap())
+            assert!(
+                matches!(action, ProposalAction::PayClaim { .. } | ProposalAction::PayPolicyClaim { .. }),
+                "only claim proposals are closed this way"
+            );
+            assert!(
+                !self.claim_disputes.contains_key(&proposal_id),
+                "appeals are closed through resolve_appeal"
+            );
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+            let end_time = proposal.get_last_time().to_instant().seconds_since_unix_epoch;
+
+            assert!(
+                now > end_time + self.appeal_voting_period_seconds,
+                "the claimant can still dispute this claim"
+            );
+            assert!(!self.proposal_passed(&proposal), "this claim was approved and is paid by executing it");
+
+            self.track_open_claim(&action, false);
+
+            for (_, inner_map) in &mut self.current_praposals {
+                inner_map.remove(&proposal_id);
+            }
+        }
+
+        // incurred but not reported reserve, a governance set share of the cover in force across risk pools
+        pub fn get_ibnr_reserve(&self) -> Decimal {
+            let exposure = self
+                .risk_pools
+                .values()
+                .fold(Decimal::zero(), |total, pool| total + pool.get_pool_info().active_exposure);
+
+            exposure * self.ibnr_ratio
+        }
+
+        // limits still open on treaties this DAO wrote as reinsurer and which are in force
+        pub fn get_reinsurance_reserve(&self) -> Decimal {
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            self.written_treaties
+                .values()
+                .filter(|treaty| treaty.in_force(now))
+                .fold(Decimal::zero(), |total, treaty| total + treaty.limit - treaty.drawn)
+        }
+
+        // case reserves, IBNR and written reinsurance limits, all held in the stable asset
+        pub fn get_total_reserves(&self) -> Decimal {
+            self.get_case_reserves() + self.get_ibnr_reserve() + self.get_reinsurance_reserve()
+        }
+
+        // stable asset held per unit of reserves, None while nothing is reserved
+        pub fn get_solvency_ratio(&self) -> Option<Decimal> {
+            let reserves = self.get_total_reserves();
+
+            if reserves.is_zero() {
+                return None;
+            }
+
+            Some(self.treasury_balance(self.stable_asset) / reserves)
+        }
+
+        pub fn get_ibnr_ratio(&self) -> Decimal {
+            self.ibnr_ratio
+        }
+
+        // treasury balance minus the claim and loss reserves and redemptions already settled but not yet collected
+        pub fn get_free_balance(&self, resource_address: ResourceAddress) -> Decimal {
+            let mut committed = *self
+                .total_claimable_redemptions
+                .get(&resource_address)
+                .unwrap_or(&Decimal::zero());
+
+            if resource_address == XRD {
+                committed += self.get_claim_reserve();
+            }
+
+            if resource_address == self.stable_asset {
+                committed += self.get_total_reserves();
+            }
+
+            let balance = self.treasury_balance(resource_address);
+
+            if balance > committed {
+                balance - committed
+            } else {
+                Decimal::zero()
+            }
+        }
+
+        pub fn get_redemption_queue(&self) -> Vec<RedemptionRequest> {
+            self.redemption_queue.clone()
+        }
+
+        pub fn get_claimable_redemptions(&self, account_address: ComponentAddress) -> HashMap<ResourceAddress, Decimal> {
+            self.claimable_redemptions
+                .get(&account_address)
+                .cloned()
+                .unwrap_or_default()
+        }
+
+        // moves queued requests, oldest first, into claimable balances as free liquidity allows
+        pub fn process_redemption_queue(&mut self) {
+            let mut index = 0;
+
+            while index < self.redemption_queue.len() {
+                let resource_address = self.redemption_queue[index].resource_address;
+
+                let available = self.get_free_balance(resource_address);
+
+                if available.is_zero() {
+                    index += 1;
+                    continue;
+                }
+
+                if !self.settle_redemption(index, available) {
+                    index += 1;
+                }
+            }
+        }
+
+        // moves up to `available` of a queued request into the claimable balance, true once it is paid off
+        fn settle_redemption(&mut self, index: usize, available: Decimal) -> bool {
+            let request = &mut self.redemption_queue[index];
+
+            let settled = request.amount_owed.min(available);
+
+            request.amount_owed -= settled;
+
+            let account_address = request.account;
+            let resource_address = request.resource_address;
+            let fully_settled = request.amount_owed.is_zero();
+
+            *self
+                .claimable_redemptions
+                .entry(account_address)
+                .or_insert_with(HashMap::new)
+                .entry(resource_address)
+                .or_insert(Decimal::zero()) += settled;
+
+            *self
+                .total_claimable_redemptions
+                .entry(resource_address)
+                .or_insert(Decimal::zero()) += settled;
+
+            if fully_settled {
+                self.redemption_queue.remove(index);
+            }
+
+            fully_settled
+        }
+
+        // the first tokens sold after a slash are the slashed ones, their proceeds go to that claimant first
+        fn settle_slashed_sales(&mut self, mut tokens_sold: Decimal, price: Decimal) {
+            while tokens_sold > Decimal::zero() && !self.slashed_token_sales.is_empty() {
+                let (request_id, tokens_left) = self.slashed_token_sales[0];
+
+                let index = match self
+                    .redemption_queue
+                    .iter()
+                    .position(|request| request.request_id == request_id)
+                {
+                    Some(index) => index,
+                    // the claim was settled from the treasury in the meantime
+                    None => {
+                        self.slashed_token_sales.remove(0);
+                        continue;
+                    }
+                };
+
+                let sold = tokens_left.min(tokens_sold);
+
+                tokens_sold -= sold;
+
+                if sold == tokens_left {
+                    self.slashed_token_sales.remove(0);
+                } else {
+                    self.slashed_token_sales[0].1 -= sold;
+                }
+
+                if self.settle_redemption(index, sold * price) {
+                    self.slashed_token_sales.retain(|(id, _)| *id != request_id);
+                }
+            }
+        }
+
+        pub fn claim_redemption(&mut self, account: Global<Account>) -> Vec<Bucket> {
+            let owner_role_of_claimer = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_claimer.rule);
+
+            let claimable = self
+                .claimable_redemptions
+                .remove(&account.address())
+                .expect("nothing to claim for this account");
+
+            let mut payouts = Vec::new();
+
+            for (resource_address, amount) in claimable {
+                *self.total_claimable_redemptions.get_mut(&resource_address).unwrap() -= amount;
+
+                payouts.push(self.withdraw_from_treasury(resource_address, amount));
+            }
+
+            payouts
+        }
+
+        fn has_queued_redemptions(&self, resource_address: ResourceAddress) -> bool {
+            self.redemption_queue
+                .iter()
+                .any(|request| request.resource_address == resource_address)
+        }
+
+        fn enqueue_redemption(&mut self, account_address: ComponentAddress, resource_address: ResourceAddress, amount_owed: Decimal) {
+            let request = RedemptionRequest {
+                request_id: self.next_redemption_id,
+                account: account_address,
+                resource_address,
+                amount_owed,
+                requested_at: Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch as u64,
+            };
+
+            self.next_redemption_id += 1;
+
+            self.redemption_queue.push(request.clone());
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::REDEMPTION_QUEUED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::RedemptionQueued(request),
+            });
+        }
+
+        pub fn get_usd_price() -> Decimal {
+            let usd_price = Runtime::get_usd_price();
+            usd_price
+        }
+
+        fn take_proposal_id(&mut self) -> usize {
+            let proposal_id = self.next_proposal_id;
+            self.next_proposal_id += 1;
+            proposal_id
+        }
+
+        pub fn create_praposal(
+            &mut self,
+            title: String,
+            description: String,
+            minimun_quorum: u8,
+            start_time: scrypto::time::UtcDateTime,
+            end_time: scrypto::time::UtcDateTime,
+            address_issued_bonds_to_sell: Option<ComponentAddress>, // Address issued ANN token to sell
+            target_xrd_amount: Option<Decimal>,
+            proposal_creator_address: Option<ComponentAddress>,
+            governance_token_or_owner_token_address: Bucket,
+            voting_type: VotingType,
+        ) -> (
+            Global<crate::proposal::pandao_praposal::TokenWeightProposal>,
+            String,
+            Bucket,
+        ) {
+            //implement proposal creation rights
+            self.assert_proposal_creation_right(&governance_token_or_owner_token_address);
+
+            use crate::proposal::pandao_praposal::TokenWeightProposal;
+
+            if let Some(address_selling_ann_tokens) = address_issued_bonds_to_sell {
+                assert!(
+                    self.ann_token.contains_key(&address_selling_ann_tokens),
+                    "The Address you have specified has not created any ANN Token"
+                );
+            }
+
+            let amount_of_tokens_should_be_minted = None;
+
+            let global_proposal_component: Global<TokenWeightProposal>;
+
+            match voting_type {
+                VotingType::ResourceHold => {
+                    (global_proposal_component, _) = TokenWeightProposal::new(
+                        title.clone(),
+                        description.clone(),
+                        minimun_quorum,
+                        start_time,
+                        end_time,
+                        self.owner_token_addresss.clone(),
+                        self.dao_token_address.clone(),
+                        address_issued_bonds_to_sell.clone(),
+                        target_xrd_amount.clone(),
+                        proposal_creator_address,
+                        amount_of_tokens_should_be_minted,
+                        VotingType::ResourceHold,
+                        None,
+                        None,
+                        Runtime::global_address(),
+                    );
+                }
+                VotingType::Equality => {
+                    (global_proposal_component, _) = TokenWeightProposal::new(
+                        title.clone(),
+                        description.clone(),
+                        minimun_quorum,
+                        start_time,
+                        end_time,
+                        self.owner_token_addresss.clone(),
+                        self.dao_token_address.clone(),
+                        address_issued_bonds_to_sell.clone(),
+                        target_xrd_amount.clone(),
+                        proposal_creator_address,
+                        amount_of_tokens_should_be_minted,
+                        VotingType::Equality,
+                        None,
+                        None,
+                        Runtime::global_address(),
+                    );
+                }
+            }
+
+            let start_time_ts: i64 = start_time.to_instant().seconds_since_unix_epoch;
+            let end_time_ts: i64 = end_time.to_instant().seconds_since_unix_epoch;
+
+            //unique-id-generation
+            let proposal_id = self.take_proposal_id();
+            //populate HashMap with newly created proposal
+
+            let inner_map = self
+                .current_praposals
+                .entry(proposal_creator_address.unwrap())
                 .or_insert_with(HashMap::new);
 
             inner_map.insert(proposal_id, global_proposal_component);
@@ -579,6 +1090,8 @@ mod radixdao {
                         governance_token_or_owner_token_address:
                             governance_token_or_owner_token_address.resource_address(),
                         token_type: VotingType::ResourceHold,
+                        action: None,
+                        linked_proposal_id: None,
                     };
 
                     let component_address = Runtime::global_address();
@@ -607,6 +1120,8 @@ mod radixdao {
                         governance_token_or_owner_token_address:
                             governance_token_or_owner_token_address.resource_address(),
                         token_type: VotingType::Equality,
+                        action: None,
+                        linked_proposal_id: None,
                     };
 
                     let component_address = Runtime::global_address();
@@ -630,6 +1145,137 @@ mod radixdao {
             )
         }
 
+        pub fn create_action_praposal(
+            &mut self,
+            title: String,
+            description: String,
+            minimun_quorum: u8,
+            start_time: scrypto::time::UtcDateTime,
+            end_time: scrypto::time::UtcDateTime,
+            action: ProposalAction,
+            proposal_creator_address: Option<ComponentAddress>,
+            governance_token_or_owner_token_address: Bucket,
+            voting_type: VotingType,
+        ) -> (
+            Global<crate::proposal::pandao_praposal::TokenWeightProposal>,
+            String,
+            Bucket,
+        ) {
+            self.assert_proposal_creation_right(&governance_token_or_owner_token_address);
+
+            assert!(
+                proposal_creator_address.is_some(),
+                "proposal creator address must be provided"
+            );
+
+            self.validate_proposal_action(&action);
+
+            let (global_proposal_component, _) = TokenWeightProposal::new(
+                title.clone(),
+                description.clone(),
+                minimun_quorum,
+                start_time,
+                end_time,
+                self.owner_token_addresss.clone(),
+                self.dao_token_address.clone(),
+                None,
+                None,
+                proposal_creator_address,
+                None,
+                voting_type.clone(),
+                Some(action.clone()),
+                None,
+                Runtime::global_address(),
+            );
+
+            let proposal_id = self.take_proposal_id();
+
+            self.current_praposals
+                .entry(proposal_creator_address.unwrap())
+                .or_insert_with(HashMap::new)
+                .insert(proposal_id, global_proposal_component);
+
+            self.track_open_claim(&action, true);
+
+            let praposal_metadata = PraposalMetadata {
+                title,
+                description,
+                minimum_quorum: minimun_quorum.into(),
+                end_time_ts: end_time.to_instant().seconds_since_unix_epoch,
+                start_time_ts: start_time.to_instant().seconds_since_unix_epoch,
+                owner_token_address: self.owner_token_addresss.clone(),
+                component_address: global_proposal_component.address(),
+                address_issued_bonds_to_sell: None,
+                target_xrd_amount: None,
+                proposal_creator_address,
+                amount_of_tokens_should_be_minted: None,
+                proposal_id,
+                governance_token_or_owner_token_address:
+                    governance_token_or_owner_token_address.resource_address(),
+                token_type: voting_type,
+                action: Some(action),
+                linked_proposal_id: None,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::PRAPOSAL,
+                dao_type: DaoType::Insurance,
+                meta_data: DaoEvent::PraposalDeployment(praposal_metadata),
+                component_address: Runtime::global_address(),
+            });
+
+            let message = format!("Proposal created with id : {}", proposal_id);
+
+            (
+                global_proposal_component,
+                message,
+                governance_token_or_owner_token_address,
+            )
+        }
+
+        fn assert_proposal_creation_right(&self, token: &Bucket) {
+            match self.proposal_creation_right {
+                ProposalCreationRight::EVERYONE => {
+                    assert_eq!(
+                        token.resource_address(),
+                        self.dao_token_address,
+                        "wrong voting token supplied! please make sure that you supply DAO Governance Token"
+                    );
+
+                    assert!(
+                        token.amount() >= Decimal::one(),
+                        "Proposal creator must have at least one governance token to create a proposal"
+                    );
+
+                    //allow proposal creation
+                }
+                ProposalCreationRight::TOKEN_HOLDER_THRESHOLD(threshold) => {
+                    assert_eq!(
+                        token.resource_address(),
+                        self.dao_token_address,
+                        "wrong voting token supplied! please make sure that you supply DAO Governance Token"
+                    );
+
+                    assert!(
+                        token.amount() >= threshold,
+                        "Proposal creator does not have enough tokens to meet the threshold"
+                    );
+                }
+                ProposalCreationRight::ADMIN => {
+                    assert_eq!(
+                        token.resource_address(),
+                        self.owner_token_addresss,
+                        "Only the admin can create a proposal and If you are an Admin please make sure you pass OWNER TOKEN ADDRESS"
+                    );
+
+                    assert!(
+                        token.amount() >= Decimal::one(),
+                        "ADMIN must pass his/her OWNER TOKEN to create proposal"
+                    );
+                }
+            }
+        }
+
         pub fn execute_proposal(&mut self, proposal_id: usize) {
             // First, find the proposal
             let mut proposal_option = None;
@@ -696,14 +1342,14 @@ mod radixdao {
                     panic!("The proposal cannot be executed due to insufficient participation");
                 }
 
-                // Check if the treasury has enough XRD
-                let treasury_balance = self.shares.amount();
-
+                // Check if the treasury has enough XRD above its reserves
                 assert!(
-                    treasury_balance >= target_xrd_amount,
-                    "Insufficient funds in the treasury to execute the proposal."
+                    target_xrd_amount <= self.get_free_balance(XRD),
+                    "only funds above the treasury reserves can be used"
                 );
 
+                self.charge_budget(BudgetCategory::Investments, XRD, target_xrd_amount);
+
                 // Create a bucket with the exact XRD amount needed for the purchase
                 let payment = self.shares.take(target_xrd_amount);
 
@@ -711,6 +1357,8 @@ mod radixdao {
                 // let remaining = self.purchase_bond(bond_creator_address, payment);
                 let remaining = self.purchase_ann_token(bond_creator_address, payment);
 
+                self.record_treasury_outflow(XRD, target_xrd_amount - remaining.amount());
+
                 // Handle remaining funds and received bond NFT
                 self.shares.put(remaining);
 
@@ -753,13 +1401,801 @@ mod radixdao {
             }
         }
 
-        pub fn vote(
-            &mut self,
-            token: Bucket,
-            againsts: bool,
-            account: Global<Account>,
+        pub fn execute_action_proposal(&mut self, proposal_id: usize) {
+            let proposal = self
+                .find_proposal(proposal_id)
+                .expect("there is no current active proposal with the given ID");
+
+            let action = proposal
+                .get_action()
+                .expect("this proposal does not carry a treasury action");
+
+            assert!(
+                !self.claim_disputes.contains_key(&proposal_id),
+                "an appeal has to be resolved before it is executed"
+            );
+
+            self.assert_proposal_passed(proposal_id, &proposal);
+
+            self.track_open_claim(&action, false);
+
+            match action.clone() {
+                ProposalAction::TransferAsset {
+                    resource_address,
+                    amount,
+                    recipient,
+                } => {
+                    self.charge_budget(BudgetCategory::Operations, resource_address, amount);
+
+                    let payment = self.withdraw_unreserved(resource_address, amount);
+
+                    let mut recipient_account: Global<Account> = Global::from(recipient);
+                    recipient_account.try_deposit_or_abort(payment, None);
+                }
+                ProposalAction::PayClaim { claimant, amount } => {
+                    self.charge_budget(BudgetCategory::Claims, self.stable_asset, amount);
+
+                    self.pay_claim(claimant, amount);
+                }
+                ProposalAction::WhitelistAsset(resource_address) => {
+                    self.whitelisted_assets.insert(resource_address);
+                }
+                ProposalAction::SetStableAsset(resource_address) => {
+                    assert!(
+                        self.whitelisted_assets.contains(&resource_address),
+                        "the stable asset must be whitelisted first"
+                    );
+                    self.assert_stable_asset_stakeable(resource_address);
+                    self.stable_asset = resource_address;
+                }
+                ProposalAction::SetClaimReserveRatio(ratio) => {
+                    self.claim_reserve_ratio = ratio;
+
+                    self.process_redemption_queue();
+                }
+                ProposalAction::MintVested { allocations } => {
+                    self.create_vesting_allocations(allocations);
+                }
+                ProposalAction::CreateStream {
+                    recipient,
+                    resource_address,
+                    rate_per_second,
+                    start_time,
+                    end_time,
+                } => {
+                    self.charge_budget(
+                        BudgetCategory::Operations,
+                        resource_address,
+                        rate_per_second * Decimal::from(end_time - start_time),
+                    );
+
+                    self.create_payment_stream(recipient, resource_address, rate_per_second, start_time, end_time);
+                }
+                ProposalAction::CancelStream { stream_id } => {
+                    self.cancel_payment_stream(stream_id);
+                }
+                ProposalAction::SetBudget {
+                    category,
+                    resource_address,
+                    cap,
+                    period,
+                } => {
+                    self.budgets.insert((category, resource_address), BudgetLimit::new(cap, period));
+                }
+                ProposalAction::RemoveBudget(category, resource_address) => {
+                    self.budgets.remove(&(category, resource_address));
+                }
+                ProposalAction::DistributeMemberRewards { resource_address, amount } => {
+                    self.charge_budget(BudgetCategory::Operations, resource_address, amount);
+
+                    self.distribute_member_rewards(resource_address, amount);
+                }
+                ProposalAction::CreateRiskPool {
+                    name,
+                    premium_rate,
+                    max_coverage,
+                    max_exposure_multiple,
+                    parametric_trigger,
+                    transferability,
+                    terms,
+                    underwriting,
+                } => {
+                    let pool = InsurancePool::instantiate_insurance_pool(
+                        Runtime::global_address(),
+                        name.clone(),
+                        self.stable_asset,
+                        premium_rate,
+                        max_coverage,
+                        max_exposure_multiple,
+                        parametric_trigger,
+                        transferability,
+                        terms,
+                        underwriting,
+                    );
+
+                    self.risk_pools.insert(name, pool);
+                }
+                ProposalAction::UpdateRiskPool {
+                    name,
+                    premium_rate,
+                    max_coverage,
+                    max_exposure_multiple,
+                } => {
+                    self.get_risk_pool(&name).update_parameters(premium_rate, max_coverage, max_exposure_multiple);
+                }
+                ProposalAction::FundRiskPool { name, amount } => {
+                    self.charge_budget(BudgetCategory::Investments, self.stable_asset, amount);
+
+                    let capital = self.withdraw_unreserved(self.stable_asset, amount);
+
+                    self.get_risk_pool(&name).add_capital(capital);
+                }
+                ProposalAction::DefundRiskPool { name, amount } => {
+                    let capital = self.get_risk_pool(&name).withdraw_capital(amount);
+
+                    self.record_treasury_inflow(capital.resource_address(), amount);
+
+                    self.deposit_to_treasury(capital);
+                }
+                ProposalAction::PayPolicyClaim {
+                    pool_name,
+                    policy_id,
+                    amount,
+                    incident_category,
+                    incident_time,
+                } => {
+                    self.pay_policy_claim(&pool_name, policy_id, amount, incident_category, incident_time);
+                }
+                ProposalAction::ConfigureAppeals {
+                    bond_ratio,
+                    quorum_multiplier,
+                    voting_period_seconds,
+                } => {
+                    self.dispute_bond_ratio = bond_ratio;
+                    self.appeal_quorum_multiplier = quorum_multiplier;
+                    self.appeal_voting_period_seconds = voting_period_seconds;
+                }
+                ProposalAction::ConfigureAssessors {
+                    committee_size,
+                    review_window_seconds,
+                    review_fee,
+                    slash_ratio,
+                    minimum_stake,
+                } => {
+                    self.assessor_committee_size = committee_size;
+                    self.review_window_seconds = review_window_seconds;
+                    self.review_fee = review_fee;
+                    self.assessor_slash_ratio = slash_ratio;
+                    self.minimum_assessor_stake = minimum_stake;
+                }
+                ProposalAction::OfferReinsurance {
+                    ceding_dao,
+                    premium,
+                    attachment_point,
+                    limit,
+                    duration_seconds,
+                } => {
+                    self.offer_reinsurance(ceding_dao, premium, attachment_point, limit, duration_seconds);
+                }
+                ProposalAction::BuyReinsurance {
+                    reinsurer,
+                    treaty_id,
+                    premium,
+                } => {
+                    self.charge_budget(BudgetCategory::Operations, self.stable_asset, premium);
+
+                    let payment = self.withdraw_unreserved(self.stable_asset, premium);
+
+                    let mut reinsurer_dao: Global<TokenWeigtedDao> = Global::from(reinsurer);
+                    let change = reinsurer_dao.bind_reinsurance(treaty_id, payment, self.aggregate_claims_paid);
+
+                    if change.amount() > Decimal::zero() {
+                        self.record_treasury_inflow(change.resource_address(), change.amount());
+                        self.deposit_to_treasury(change);
+                    } else {
+                        change.drop_empty();
+                    }
+
+                    self.reinsurance_cover.push((reinsurer, treaty_id));
+                }
+                ProposalAction::ConfigureStaking {
+                    reward_share,
+                    cooldown_seconds,
+                } => {
+                    self.staking_reward_share = reward_share;
+                    self.staking_cooldown_seconds = cooldown_seconds;
+                }
+                ProposalAction::RebalanceTreasury {
+                    pool,
+                    input_resource,
+                    input_amount,
+                    min_output,
+                } => {
+                    self.charge_budget(BudgetCategory::Investments, input_resource, input_amount);
+
+                    self.rebalance_treasury(pool, input_resource, input_amount, min_output);
+                }
+                ProposalAction::WhitelistSwapPool(pool) => {
+                    self.swap_pools.insert(pool);
+                }
+                ProposalAction::SurrenderAnnuity(issuer) => {
+                    if let Err(message) = self.surrender_ann_token(issuer) {
+                        panic!("{}", message);
+                    }
+                }
+                ProposalAction::SetIbnrRatio(ratio) => {
+                    self.ibnr_ratio = ratio;
+
+                    self.process_redemption_queue();
+                }
+                ProposalAction::ApprovePolicyTransfer {
+                    pool_name,
+                    policy_id,
+                    new_holder,
+                } => {
+                    let mut pool = self.get_risk_pool(&pool_name);
+
+                    let holder = pool.get_policy(policy_id.clone()).holder;
+                    let permit = pool.approve_transfer(policy_id, new_holder);
+
+                    let mut holder_account: Global<Account> = Global::from(holder);
+                    holder_account.try_deposit_or_abort(permit, None);
+                }
+            }
+
+            let event_metadata = ProposalActionExecuted {
+                praposal_address: proposal.address(),
+                proposal_id,
+                action,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::EXECUTE_PROPOSAL,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ProposalActionExecuted(event_metadata),
+            });
+
+            for (_, inner_map) in &mut self.current_praposals {
+                inner_map.remove(&proposal_id);
+            }
+        }
+
+        fn find_proposal(&self, proposal_id: usize) -> Option<Global<TokenWeightProposal>> {
+            for (_, inner_map) in &self.current_praposals {
+                if let Some(proposal) = inner_map.get(&proposal_id) {
+                    return Some(proposal.clone());
+                }
+            }
+            None
+        }
+
+        // voting has ended, quorum was reached and more weight voted for than against
+        fn assert_proposal_passed(&self, proposal_id: usize, proposal: &Global<TokenWeightProposal>) {
+            let current_time_seconds: i64 =
+                Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let end_time_seconds = proposal.get_last_time().to_instant().seconds_since_unix_epoch;
+
+            assert!(
+                current_time_seconds > end_time_seconds,
+                "Proposal can only be executed after the specified end time"
+            );
+
+            let number_of_voters = proposal.get_number_of_voters();
+            let minimum_quorum = proposal.get_minimum_quorum();
+
+            assert!(
+                number_of_voters >= minimum_quorum,
+                "The proposal cannot be executed due to insufficient participation"
+            );
+
+            assert!(
+                proposal.get_voted_for() > proposal.get_voted_against(),
+                "The proposal was not approved by the voters"
+            );
+
+            let event_metadata = ProposalQuorumMet {
+                proposal_id,
+                minimum_quorum,
+                number_of_voters,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::QUORUM_MET,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ProposalQuorumMet(event_metadata),
+            });
+        }
+
+        fn proposal_passed(&self, proposal: &Global<TokenWeightProposal>) -> bool {
+            proposal.get_number_of_voters() >= proposal.get_minimum_quorum()
+                && proposal.get_voted_for() > proposal.get_voted_against()
+        }
+
+        // the claimant of a rejected claim posts a bond to have it voted on again under stricter rules
+        pub fn dispute_claim(
+            &mut self,
             proposal_id: usize,
-        ) -> Bucket {
+            mut bond: Bucket,
+            account: Global<Account>,
+            policy: Option<Proof>,
+        ) -> (Global<TokenWeightProposal>, Bucket) {
+            let owner_role_of_claimant = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_claimant.rule);
+
+            let proposal = self
+                .find_proposal(proposal_id)
+                .expect("there is no current active proposal with the given ID");
+
+            let action = proposal
+                .get_action()
+                .expect("this proposal does not carry a treasury action");
+
+            let (claimant, claim_amount) = match &action {
+                ProposalAction::PayClaim { claimant, amount } => (*claimant, *amount),
+                // policy claims are disputed by whoever presents the policy now
+                ProposalAction::PayPolicyClaim {
+                    pool_name,
+                    policy_id,
+                    amount,
+                    ..
+                } => {
+                    let policy = policy.expect("a policy claim is disputed by presenting the policy");
+
+                    assert!(
+                        self.claimant_policy_id(pool_name, policy, &account) == *policy_id,
+                        "this policy is not the one claimed on"
+                    );
+
+                    (account.address(), *amount)
+                }
+                _ => panic!("only claim proposals can be disputed"),
+            };
+
+            assert!(claimant == account.address(), "only the claimant can dispute this claim");
+
+            assert!(
+                proposal.get_linked_proposal().is_none(),
+                "an appeal can not be appealed again"
+            );
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            assert!(
+                now > proposal.get_last_time().to_instant().seconds_since_unix_epoch,
+                "the claim is still being voted on"
+            );
+            assert!(!self.proposal_passed(&proposal), "this claim was not rejected");
+
+            assert!(bond.resource_address() == self.stable_asset, "the bond must be paid in the stable asset");
+
+            let bond_amount = claim_amount * self.dispute_bond_ratio;
+
+            assert!(bond.amount() >= bond_amount, "insufficient dispute bond");
+
+            if self.dispute_bonds.get(&self.stable_asset).is_some() {
+                self.dispute_bonds.get_mut(&self.stable_asset).unwrap().put(bond.take(bond_amount));
+            } else {
+                self.dispute_bonds.insert(self.stable_asset, Vault::with_bucket(bond.take(bond_amount)));
+            }
+
+            let appeal_quorum = (proposal.get_minimum_quorum() * self.appeal_quorum_multiplier as usize)
+                .min(u8::MAX as usize) as u8;
+
+            let start_time = UtcDateTime::from_instant(&Instant::new(now)).unwrap();
+            let appeal_end_time = now + self.appeal_voting_period_seconds;
+            let end_time = UtcDateTime::from_instant(&Instant::new(appeal_end_time)).unwrap();
+
+            let title = format!("Appeal of claim proposal {}", proposal_id);
+            let description = format!("The claimant disputes the rejection of claim proposal {}", proposal_id);
+
+            // the DAO opens the appeal itself, the bond stands in for the usual proposal creation right
+            let (appeal, _) = TokenWeightProposal::new(
+                title.clone(),
+                description.clone(),
+                appeal_quorum,
+                start_time,
+                end_time,
+                self.owner_token_addresss.clone(),
+                self.dao_token_address.clone(),
+                None,
+                None,
+                Some(claimant),
+                None,
+                proposal.get_voting_type(),
+                Some(action.clone()),
+                Some(proposal_id),
+                Runtime::global_address(),
+            );
+
+            let appeal_proposal_id = self.take_proposal_id();
+
+            for (_, inner_map) in &mut self.current_praposals {
+                inner_map.remove(&proposal_id);
+            }
+
+            self.current_praposals
+                .entry(claimant)
+                .or_insert_with(HashMap::new)
+                .insert(appeal_proposal_id, appeal);
+
+            let dispute = ClaimDispute {
+                rejected_proposal_id: proposal_id,
+                appeal_proposal_id,
+                claimant,
+                bond_resource: self.stable_asset,
+                bond_amount,
+                appeal_end_time,
+            };
+
+            self.claim_disputes.insert(appeal_proposal_id, dispute.clone());
+
+            let praposal_metadata = PraposalMetadata {
+                title,
+                description,
+                minimum_quorum: appeal_quorum.into(),
+                end_time_ts: appeal_end_time,
+                start_time_ts: now,
+                owner_token_address: self.owner_token_addresss.clone(),
+                component_address: appeal.address(),
+                address_issued_bonds_to_sell: None,
+                target_xrd_amount: None,
+                proposal_creator_address: Some(claimant),
+                amount_of_tokens_should_be_minted: None,
+                proposal_id: appeal_proposal_id,
+                governance_token_or_owner_token_address: self.dao_token_address,
+                token_type: proposal.get_voting_type(),
+                action: Some(action),
+                linked_proposal_id: Some(proposal_id),
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::PRAPOSAL,
+                dao_type: DaoType::Insurance,
+                meta_data: DaoEvent::PraposalDeployment(praposal_metadata),
+                component_address: Runtime::global_address(),
+            });
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::CLAIM_DISPUTED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ClaimDisputed(dispute),
+            });
+
+            (appeal, bond)
+        }
+
+        // upheld appeals return the bond and pay the claim, failed ones forfeit the bond to the treasury
+        pub fn resolve_appeal(&mut self, appeal_proposal_id: usize) {
+            let dispute = self
+                .claim_disputes
+                .remove(&appeal_proposal_id)
+                .expect("there is no open appeal with the given ID");
+
+            let appeal = self
+                .find_proposal(appeal_proposal_id)
+                .expect("there is no current active proposal with the given ID");
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            assert!(now > dispute.appeal_end_time, "the appeal is still being voted on");
+
+            let bond = self
+                .dispute_bonds
+                .get_mut(&dispute.bond_resource)
+                .unwrap()
+                .take(dispute.bond_amount);
+
+            let upheld = self.proposal_passed(&appeal);
+
+            // an upheld claim is paid by executing the appeal separately, so a failed payout can't lock the bond
+            if upheld {
+                let mut claimant_account: Global<Account> = Global::from(dispute.claimant);
+                claimant_account.try_deposit_or_abort(bond, None);
+            } else {
+                self.record_treasury_inflow(dispute.bond_resource, dispute.bond_amount);
+                self.deposit_to_treasury(bond);
+
+                if let Some(action) = appeal.get_action() {
+                    self.track_open_claim(&action, false);
+                }
+
+                for (_, inner_map) in &mut self.current_praposals {
+                    inner_map.remove(&appeal_proposal_id);
+                }
+            }
+
+            let event_metadata = AppealResolved {
+                appeal_proposal_id,
+                rejected_proposal_id: dispute.rejected_proposal_id,
+                claimant: dispute.claimant,
+                upheld,
+                bond_amount: dispute.bond_amount,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::APPEAL_RESOLVED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::AppealResolved(event_metadata),
+            });
+        }
+
+        pub fn get_claim_disputes(&self) -> HashMap<usize, ClaimDispute> {
+            self.claim_disputes.clone()
+        }
+
+        fn validate_proposal_action(&self, action: &ProposalAction) {
+            match action {
+                ProposalAction::TransferAsset { resource_address, amount, .. } => {
+                    assert!(
+                        self.whitelisted_assets.contains(resource_address),
+                        "only whitelisted assets can be transferred from the treasury"
+                    );
+                    assert!(*amount > Decimal::zero(), "transfer amount must be positive");
+                }
+                ProposalAction::PayClaim { amount, .. } => {
+                    assert!(*amount > Decimal::zero(), "claim amount must be positive");
+                }
+                ProposalAction::WhitelistAsset(resource_address) => {
+                    let resource_manager = ResourceManager::from(*resource_address);
+                    assert!(
+                        resource_manager.resource_type().is_fungible(),
+                        "only fungible resources can be held in the treasury"
+                    );
+                }
+                ProposalAction::SetStableAsset(resource_address) => {
+                    self.assert_stable_asset_stakeable(*resource_address);
+                }
+                ProposalAction::SetClaimReserveRatio(ratio) => {
+                    assert!(
+                        *ratio >= Decimal::zero() && *ratio <= Decimal::one(),
+                        "claim reserve ratio must be between 0 and 1"
+                    );
+                }
+                ProposalAction::SetIbnrRatio(ratio) => {
+                    assert!(
+                        *ratio >= Decimal::zero() && *ratio <= Decimal::one(),
+                        "IBNR ratio must be between 0 and 1"
+                    );
+                }
+                ProposalAction::CreateStream {
+                    resource_address,
+                    rate_per_second,
+                    start_time,
+                    end_time,
+                    ..
+                } => {
+                    assert!(
+                        self.whitelisted_assets.contains(resource_address),
+                        "only whitelisted assets can be streamed from the treasury"
+                    );
+                    assert!(*rate_per_second > Decimal::zero(), "stream rate must be positive");
+                    assert!(end_time > start_time, "stream must end after it starts");
+                }
+                ProposalAction::SetBudget {
+                    resource_address,
+                    cap,
+                    period,
+                    ..
+                } => {
+                    assert!(
+                        self.whitelisted_assets.contains(resource_address),
+                        "budgets can only be set for whitelisted assets"
+                    );
+                    assert!(*cap >= Decimal::zero(), "budget cap can not be negative");
+
+                    if let BudgetPeriod::Epochs(length) = period {
+                        assert!(*length > 0, "budget period must be at least one epoch");
+                    }
+                }
+                ProposalAction::RemoveBudget(..) => {}
+                ProposalAction::ConfigureAppeals {
+                    bond_ratio,
+                    quorum_multiplier,
+                    voting_period_seconds,
+                } => {
+                    assert!(*bond_ratio >= Decimal::zero(), "bond ratio can not be negative");
+                    assert!(*quorum_multiplier >= 1, "appeals can not need less quorum than the original vote");
+                    assert!(*voting_period_seconds > 0, "appeal voting period must be positive");
+                }
+                ProposalAction::CreateRiskPool {
+                    name,
+                    premium_rate,
+                    max_coverage,
+                    max_exposure_multiple,
+                    terms,
+                    underwriting,
+                    ..
+                } => {
+                    assert!(!self.risk_pools.contains_key(name), "a risk pool with this name already exists");
+                    assert!(*premium_rate > Decimal::zero(), "premium rate must be positive");
+                    assert!(*max_coverage >= Decimal::zero(), "max coverage can not be negative");
+                    assert!(*max_exposure_multiple > Decimal::zero(), "exposure multiple must be positive");
+
+                    terms.assert_valid();
+
+                    assert!(underwriting.waiting_period_seconds >= 0, "waiting period can not be negative");
+                    assert!(underwriting.max_cover_per_account > Decimal::zero(), "cover cap per account must be positive");
+                    assert!(underwriting.cover_window_epochs > 0, "cover window must span at least one epoch");
+                }
+                ProposalAction::UpdateRiskPool {
+                    name,
+                    premium_rate,
+                    max_coverage,
+                    max_exposure_multiple,
+                } => {
+                    assert!(self.risk_pools.contains_key(name), "no risk pool with this name");
+                    assert!(*premium_rate > Decimal::zero(), "premium rate must be positive");
+                    assert!(*max_coverage >= Decimal::zero(), "max coverage can not be negative");
+                    assert!(*max_exposure_multiple > Decimal::zero(), "exposure multiple must be positive");
+                }
+                ProposalAction::FundRiskPool { name, amount }
+                | ProposalAction::DefundRiskPool { name, amount } => {
+                    assert!(self.risk_pools.contains_key(name), "no risk pool with this name");
+                    assert!(*amount > Decimal::zero(), "amount must be positive");
+                }
+                ProposalAction::PayPolicyClaim {
+                    pool_name,
+                    policy_id,
+                    amount,
+                    incident_category,
+                    incident_time,
+                } => {
+                    assert!(*amount > Decimal::zero(), "claim amount must be positive");
+
+                    let pool = self.get_risk_pool(pool_name);
+
+                    assert!(
+                        !pool.get_policy(policy_id.clone()).terms.excludes(incident_category),
+                        "this incident category is excluded from the policy"
+                    );
+
+                    pool.assert_claimable(policy_id.clone(), *incident_time);
+                }
+                ProposalAction::ConfigureAssessors {
+                    committee_size,
+                    review_window_seconds,
+                    review_fee,
+                    slash_ratio,
+                    minimum_stake,
+                } => {
+                    assert!(*committee_size > 0, "a review committee needs at least one assessor");
+                    assert!(*review_window_seconds > 0, "review window must be positive");
+                    assert!(*review_fee >= Decimal::zero(), "review fee can not be negative");
+                    assert!(
+                        *slash_ratio >= Decimal::zero() && *slash_ratio <= Decimal::one(),
+                        "slash ratio must be between 0 and 1"
+                    );
+                    assert!(*minimum_stake > Decimal::zero(), "minimum assessor stake must be positive");
+                }
+                ProposalAction::OfferReinsurance {
+                    ceding_dao,
+                    premium,
+                    attachment_point,
+                    limit,
+                    duration_seconds,
+                } => {
+                    assert!(
+                        *ceding_dao != Runtime::global_address(),
+                        "a DAO can not reinsure itself"
+                    );
+                    assert!(
+                        Self::is_dao_instance(*ceding_dao),
+                        "reinsurance can only be written for DAOs of this blueprint"
+                    );
+                    assert!(*premium >= Decimal::zero(), "premium can not be negative");
+                    assert!(*attachment_point >= Decimal::zero(), "attachment point can not be negative");
+                    assert!(*limit > Decimal::zero(), "limit must be positive");
+                    assert!(*duration_seconds > 0, "treaty duration must be positive");
+                }
+                ProposalAction::BuyReinsurance { reinsurer, premium, .. } => {
+                    assert!(
+                        Self::is_dao_instance(*reinsurer),
+                        "reinsurance can only be bought from DAOs of this blueprint"
+                    );
+                    assert!(
+                        self.treasury_balance(self.stable_asset) >= *premium,
+                        "Insufficient funds in the treasury to execute the proposal."
+                    );
+                }
+                ProposalAction::ConfigureStaking {
+                    reward_share,
+                    cooldown_seconds,
+                } => {
+                    assert!(
+                        *reward_share >= Decimal::zero() && *reward_share <= Decimal::one(),
+                        "staking reward share must be between 0 and 1"
+                    );
+                    assert!(*cooldown_seconds >= 0, "cooldown can not be negative");
+                }
+                ProposalAction::RebalanceTreasury {
+                    pool,
+                    input_resource,
+                    input_amount,
+                    min_output,
+                } => {
+                    assert!(self.swap_pools.contains(pool), "only whitelisted swap pools can be used");
+                    assert!(
+                        self.whitelisted_assets.contains(input_resource),
+                        "only whitelisted assets can be swapped out of the treasury"
+                    );
+                    assert!(*input_amount > Decimal::zero(), "swap amount must be positive");
+                    assert!(*min_output > Decimal::zero(), "minimum output must be positive");
+                }
+                ProposalAction::WhitelistSwapPool(pool) => {
+                    let swap_pool: Global<SwapPool> = Global::from(*pool);
+
+                    assert!(
+                        swap_pool
+                            .get_reserves()
+                            .keys()
+                            .all(|resource_address| self.whitelisted_assets.contains(resource_address)),
+                        "a swap pool may only trade whitelisted assets"
+                    );
+                }
+                ProposalAction::SurrenderAnnuity(issuer) => {
+                    assert!(
+                        self.ann_token.contains_key(issuer),
+                        "No ANN Token created by the specified address."
+                    );
+                }
+                ProposalAction::ApprovePolicyTransfer {
+                    pool_name,
+                    policy_id,
+                    ..
+                } => {
+                    let pool = self.get_risk_pool(pool_name);
+
+                    assert!(
+                        pool.get_pool_info().transferability == PolicyTransferability::DaoApproved,
+                        "policies of this pool are not transferred through DAO approval"
+                    );
+
+                    assert!(
+                        pool.get_policy_status(policy_id.clone()) != PolicyStatus::Lapsed,
+                        "lapsed policies can not be transferred"
+                    );
+                }
+                ProposalAction::DistributeMemberRewards { resource_address, amount } => {
+                    assert!(
+                        self.whitelisted_assets.contains(resource_address),
+                        "only whitelisted assets can be distributed as rewards"
+                    );
+                    assert!(*amount > Decimal::zero(), "reward amount must be positive");
+                    assert!(!self.member_badges.is_empty(), "there are no members to reward");
+                }
+                ProposalAction::CancelStream { stream_id } => {
+                    let stream = self
+                        .payment_streams
+                        .get(stream_id)
+                        .expect("no payment stream with the given ID");
+                    assert!(stream.cancelled_at.is_none(), "this payment stream is already cancelled");
+                }
+                ProposalAction::MintVested { allocations } => {
+                    assert!(!allocations.is_empty(), "a vesting proposal needs at least one allocation");
+
+                    for allocation in allocations {
+                        assert!(allocation.amount > Decimal::zero(), "vesting amount must be positive");
+                        assert!(
+                            allocation.cliff_seconds >= 0 && allocation.vesting_seconds >= allocation.cliff_seconds,
+                            "the cliff must fall within the vesting period"
+                        );
+                    }
+                }
+            }
+        }
+
+        pub fn vote(
+            &mut self,
+            token: Bucket,
+            againsts: bool,
+            account: Global<Account>,
+            proposal_id: usize,
+        ) {
             let owner_role_of_voter = account.get_owner_role();
             Runtime::assert_access_rule(owner_role_of_voter.rule);
 
@@ -807,15 +2243,10 @@ mod radixdao {
                     meta_data: DaoEvent::PraposalVote(event_metadata),
                 });
 
-                let result = proposal.vote(token, againsts);
-
-                // Mark this voter as having voted
-                proposal.set_vote_caster_address(voter_address);
-
-                result
+                // the proposal keeps the tokens until voting ends, see release_votes
+                proposal.vote(token, againsts, voter_address);
             } else {
-                assert!(false, "no active proposal with the given ID");
-                panic!();
+                panic!("no active proposal with the given ID");
             }
         }
 
@@ -834,6 +2265,8 @@ mod radixdao {
             price: Decimal,
             number_of_annuities_to_mint: Decimal,
             your_address: ComponentAddress, //      ISSUER ADDRESS
+            surrender_penalty: Decimal,
+            rate_reset: Option<RateResetTerms>,
         ) -> Global<Annuity> {
 
             // assert!(
@@ -853,6 +2286,8 @@ mod radixdao {
                 annuity_position.clone(),
                 price.clone(),
                 number_of_annuities_to_mint.clone(),
+                surrender_penalty,
+                rate_reset.clone(),
             );
 
             self.ann_token
@@ -874,6 +2309,8 @@ mod radixdao {
                 price,
                 number_of_annuities_to_mint,
                 your_address,
+                surrender_penalty,
+                rate_reset,
             };
 
             Runtime::emit_event(PandaoEvent {
@@ -1035,96 +2472,1364 @@ mod radixdao {
         //         "No bonds created by the specified address."
         //     );
 
-        //     // Retrieve the most recent bond component created by the bond creator
-        //     let bond_components = self.ann_token.get(&bond_creator_address).unwrap();
-        //     let latest_bond_component = bond_components.last().expect("No bond component found");
+        //     // Retrieve the most recent bond component created by the bond creator
+        //     let bond_components = self.ann_token.get(&bond_creator_address).unwrap();
+        //     let latest_bond_component = bond_components.last().expect("No bond component found");
+
+        //     // Get bond details of the latest bond component
+        //     latest_bond_component.get_bond_details()
+        // }
+
+        // Function to retrieve bond creators and their bond component addresses
+        pub fn get_bond_creators(&self) -> HashMap<ComponentAddress, Vec<Global<Annuity>>> {
+            self.ann_token.clone() // Return the HashMap of bond creators and their bonds
+        }
+
+        // New function to get all bond creator addresses
+        pub fn get_bond_creator_addresses(&self) -> Vec<ComponentAddress> {
+            self.ann_token.keys().cloned().collect() // Return a list of bond creator addresses
+        }
+
+        // Function to get bond creator address and bond details
+        // pub fn get_bond_creator_and_details(&self) -> Vec<(ComponentAddress, Vec<BondDetails>)> {
+        //     let mut result = Vec::new();
+
+        //     // Iterate through each bond creator address and their bond components
+        //     for (creator_address, bonds) in &self.ann_token {
+        //         let mut bond_details = Vec::new();
+        //         for bond in bonds {
+        //             bond_details.push(bond.get_bond_details());
+        //         }
+        //         // Push the creator address and corresponding bond details to the result
+        //         result.push((*creator_address, bond_details));
+        //     }
+
+        //     result
+        // }
+
+        pub fn send_money_to_dao_treasury(
+            &mut self,
+            payment: Bucket,
+            account: Global<Account>,
+        ) {
+            // Ensure the payment is in XRD
+            assert_eq!(
+                payment.resource_address(),
+                XRD,
+                "Only XRD tokens are accepted for treasury contributions"
+            );
+
+            // Get the amount being sent
+            let amount = payment.amount();
+
+            // Get the sender's address
+            let sender_address = account.address();
+
+            // Store the payment in the XRD treasury vault
+            self.shares.put(payment);
+
+            self.record_treasury_inflow(XRD, amount);
+
+            self.process_redemption_queue();
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch as u64;
+
+            // Update the contributor's record
+            self.update_contributor_record(sender_address, amount, now);
+
+            self.issue_or_update_member_badge(account, now);
+
+            // Emit an event for the contribution
+            self.emit_contribution_event(sender_address, amount, now);
+        }
+
+        // Helper method to update the contributor's record
+        fn update_contributor_record(&mut self, address: ComponentAddress, amount: Decimal, timestamp: u64) {
+            let record = self.contributors.entry(address).or_insert(ContributorRecord {
+                total_contributed: Decimal::zero(),
+                number_of_contributions: 0,
+                first_contribution_at: timestamp,
+                last_contribution_at: timestamp,
+            });
+
+            record.total_contributed += amount;
+            record.number_of_contributions += 1;
+            record.last_contribution_at = timestamp;
+        }
+
+        // mints a membership badge on the first contribution, afterwards keeps its totals and tier current
+        fn issue_or_update_member_badge(&mut self, account: Global<Account>, timestamp: u64) {
+            let member = account.address();
+
+            let cumulative_contribution = self.contributors.get(&member).unwrap().total_contributed;
+
+            let tier = MembershipTier::for_contribution(cumulative_contribution);
+
+            if let Some(badge_id) = self.member_badges.get(&member) {
+                self.member_badge_manager.update_non_fungible_data(
+                    badge_id,
+                    "cumulative_contribution",
+                    cumulative_contribution,
+                );
+                self.member_badge_manager
+                    .update_non_fungible_data(badge_id, "tier", tier);
+                return;
+            }
+
+            let badge_id = NonFungibleLocalId::integer(self.member_badges.len() as u64);
+
+            let badge_data = MemberBadge {
+                member,
+                joined_at: timestamp,
+                cumulative_contribution,
+                tier,
+            };
+
+            let badge = self
+                .member_badge_manager
+                .mint_non_fungible(&badge_id, badge_data.clone());
+
+            self.member_badges.insert(member, badge_id);
+
+            let mut member_account = account;
+            member_account.try_deposit_or_abort(badge.into(), None);
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::MEMBER_BADGE_ISSUED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::MemberBadgeIssued(badge_data),
+            });
+        }
+
+        // splits `amount` across badge holders in proportion to what each contributed
+        fn distribute_member_rewards(&mut self, resource_address: ResourceAddress, amount: Decimal) {
+            let total_contributed = self
+                .contributors
+                .values()
+                .fold(Decimal::zero(), |total, record| total + record.total_contributed);
+
+            assert!(total_contributed > Decimal::zero(), "there are no members to reward");
+
+            let rewards = self.withdraw_unreserved(resource_address, amount);
+
+            if self.member_reward_vaults.get(&resource_address).is_some() {
+                self.member_reward_vaults.get_mut(&resource_address).unwrap().put(rewards);
+            } else {
+                self.member_reward_vaults.insert(resource_address, Vault::with_bucket(rewards));
+            }
+
+            for (member, badge_id) in &self.member_badges {
+                let contributed = self.contributors.get(member).unwrap().total_contributed;
+
+                *self
+                    .member_rewards
+                    .entry(badge_id.clone())
+                    .or_insert_with(HashMap::new)
+                    .entry(resource_address)
+                    .or_insert(Decimal::zero()) += amount * contributed / total_contributed;
+            }
+
+            let event_metadata = MemberRewardsDistributed {
+                resource_address,
+                amount,
+                number_of_members: self.member_badges.len(),
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::MEMBER_REWARDS_DISTRIBUTED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::MemberRewardsDistributed(event_metadata),
+            });
+        }
+
+        pub fn claim_member_rewards(&mut self, member_badge: Bucket) -> (Bucket, Vec<Bucket>) {
+            assert_eq!(
+                member_badge.resource_address(),
+                self.member_badge_manager.address(),
+                "wrong membership badge supplied"
+            );
+
+            let badge_id = member_badge.as_non_fungible().non_fungible_local_id();
+
+            let rewards = self
+                .member_rewards
+                .remove(&badge_id)
+                .expect("there are no rewards to claim for this badge");
+
+            let mut payouts = Vec::new();
+
+            for (resource_address, amount) in rewards {
+                payouts.push(
+                    self.member_reward_vaults
+                        .get_mut(&resource_address)
+                        .unwrap()
+                        .take(amount),
+                );
+            }
+
+            (member_badge, payouts)
+        }
+
+        pub fn get_member_badge_id(&self, member: ComponentAddress) -> Option<NonFungibleLocalId> {
+            self.member_badges.get(&member).cloned()
+        }
+
+        pub fn get_member_rewards(&self, badge_id: NonFungibleLocalId) -> HashMap<ResourceAddress, Decimal> {
+            self.member_rewards.get(&badge_id).cloned().unwrap_or_default()
+        }
+
+        // Helper method to emit a contribution event
+        fn emit_contribution_event(&self, address: ComponentAddress, amount: Decimal, timestamp: u64) {
+            let event_metadata = TreasuryContribution {
+                contributor: address,
+                amount: amount,
+                timestamp,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::TREASURY_CONTRIBUTION,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::TreasuryContribution(event_metadata),
+            });
+        }
+
+        // Method to get all contributors and their total contributions
+        pub fn get_all_contributors(&self) -> HashMap<ComponentAddress, Decimal> {
+            self.contributors
+                .iter()
+                .map(|(address, record)| (*address, record.total_contributed))
+                .collect()
+        }
+
+        pub fn get_contributor_records(&self) -> HashMap<ComponentAddress, ContributorRecord> {
+            self.contributors.clone()
+        }
+
+        fn record_treasury_inflow(&mut self, resource_address: ResourceAddress, amount: Decimal) {
+            *self.treasury_inflows.entry(resource_address).or_insert(Decimal::zero()) += amount;
+        }
+
+        fn record_treasury_outflow(&mut self, resource_address: ResourceAddress, amount: Decimal) {
+            *self.treasury_outflows.entry(resource_address).or_insert(Decimal::zero()) += amount;
+        }
+
+        // balances, lifetime inflows/outflows and XRD committed to proposals that are still pending
+        pub fn get_treasury_report(&self) -> TreasuryReport {
+            let mut balances: HashMap<ResourceAddress, Decimal> = HashMap::new();
+
+            balances.insert(XRD, self.shares.amount());
+
+            for resource_address in &self.whitelisted_assets {
+                balances.insert(*resource_address, self.treasury_balance(*resource_address));
+            }
+
+            for (resource_address, vault) in &self.ann_tokens {
+                balances.insert(*resource_address, vault.amount());
+            }
+
+            let mut committed_obligations = Decimal::zero();
+
+            for (_, inner_map) in &self.current_praposals {
+                for (_, proposal) in inner_map {
+                    committed_obligations += proposal.get_committed_xrd_amount();
+                }
+            }
+
+            TreasuryReport {
+                balances,
+                inflows: self.treasury_inflows.clone(),
+                outflows: self.treasury_outflows.clone(),
+                committed_obligations,
+                reported_at: Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch as u64,
+            }
+        }
+
+        // accepts any whitelisted fungible into the treasury
+        pub fn fund_treasury(&mut self, payment: Bucket) {
+            let resource_address = payment.resource_address();
+            let amount = payment.amount();
+
+            self.deposit_to_treasury(payment);
+
+            self.record_treasury_inflow(resource_address, amount);
+
+            self.process_redemption_queue();
+        }
+
+        pub fn pay_premium(&mut self, payment: Bucket, account: Global<Account>) {
+            assert_eq!(
+                payment.resource_address(),
+                self.stable_asset,
+                "premiums must be paid in the DAO's stable asset"
+            );
+
+            let amount = payment.amount();
+
+            let treasury_share = self.share_with_stakers(payment);
+
+            let treasury_amount = treasury_share.amount();
+
+            self.deposit_to_treasury(treasury_share);
+
+            self.record_treasury_inflow(self.stable_asset, treasury_amount);
+
+            self.premiums_collected += amount;
+
+            self.process_redemption_queue();
+
+            let event_metadata = PremiumPaid {
+                payer: account.address(),
+                resource_address: self.stable_asset,
+                amount,
+                total_premiums_collected: self.premiums_collected,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::PREMIUM_PAID,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::PremiumPaid(event_metadata),
+            });
+        }
+
+        pub fn stake(&mut self, tokens: Bucket, account: Global<Account>) {
+            let owner_role_of_staker = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_staker.rule);
+
+            assert_eq!(
+                tokens.resource_address(),
+                self.dao_token_address,
+                "only the DAO governance token can be staked"
+            );
+
+            // slashed stakes are sold for XRD, so they can only back claims paid in XRD
+            assert!(
+                self.stable_asset == XRD,
+                "staking is only open while claims are paid in XRD"
+            );
+
+            let amount = tokens.amount();
+
+            assert!(amount > Decimal::zero(), "nothing to stake");
+
+            let position = self
+                .stakes
+                .entry(account.address())
+                .or_insert_with(StakePosition::new);
+
+            position.settle(&self.reward_per_token);
+            position.staked_amount += amount;
+
+            self.staking_vault.put(tokens);
+
+            self.total_staked += amount;
+
+            self.emit_staking_event(EventType::STAKED, account.address(), amount);
+        }
+
+        // stops earning on `amount` right away, the tokens can be withdrawn once the cooldown passed
+        pub fn unstake(&mut self, amount: Decimal, account: Global<Account>) {
+            let owner_role_of_staker = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_staker.rule);
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let position = self
+                .stakes
+                .get_mut(&account.address())
+                .expect("this account has no stake");
+
+            assert!(
+                amount > Decimal::zero() && amount <= position.staked_amount,
+                "can not unstake more than is staked"
+            );
+
+            position.settle(&self.reward_per_token);
+            position.staked_amount -= amount;
+            position.unstaking_amount += amount;
+            position.unstake_available_at = now + self.staking_cooldown_seconds;
+
+            self.total_staked -= amount;
+
+            self.emit_staking_event(EventType::UNSTAKED, account.address(), amount);
+        }
+
+        pub fn withdraw_unstaked(&mut self, account: Global<Account>) -> Bucket {
+            let owner_role_of_staker = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_staker.rule);
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let position = self
+                .stakes
+                .get_mut(&account.address())
+                .expect("this account has no stake");
+
+            assert!(position.unstaking_amount > Decimal::zero(), "nothing is being unstaked");
+            assert!(now >= position.unstake_available_at, "the unstaking cooldown has not passed yet");
+
+            let amount = position.unstaking_amount;
+            position.unstaking_amount = Decimal::zero();
+
+            self.staking_vault.take(amount)
+        }
+
+        pub fn claim_rewards(&mut self, account: Global<Account>) -> Vec<Bucket> {
+            let owner_role_of_staker = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_staker.rule);
+
+            let position = self
+                .stakes
+                .get_mut(&account.address())
+                .expect("this account has no stake");
+
+            position.settle(&self.reward_per_token);
+
+            let rewards = std::mem::take(&mut position.accrued_rewards);
+
+            let mut payouts = Vec::new();
+
+            for (resource_address, amount) in &rewards {
+                payouts.push(
+                    self.staking_reward_vaults
+                        .get_mut(resource_address)
+                        .unwrap()
+                        .take(*amount),
+                );
+            }
+
+            let event_metadata = StakingRewardsClaimed {
+                staker: account.address(),
+                rewards,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::STAKING_REWARDS_CLAIMED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::StakingRewardsClaimed(event_metadata),
+            });
+
+            payouts
+        }
+
+        pub fn get_stake(&self, staker: ComponentAddress) -> Option<StakePosition> {
+            self.stakes.get(&staker).map(|position| {
+                let mut position = position.clone();
+                position.settle(&self.reward_per_token);
+                position
+            })
+        }
+
+        pub fn get_total_staked(&self) -> Decimal {
+            self.total_staked
+        }
+
+        // pays what the treasury holds; any shortfall is borne by stakers and queued for the claimant
+        fn pay_claim(&mut self, claimant: ComponentAddress, amount: Decimal) {
+            let stable_asset = self.stable_asset;
+
+            self.aggregate_claims_paid += amount;
+
+            let earmarked = self
+                .total_claimable_redemptions
+                .get(&stable_asset)
+                .cloned()
+                .unwrap_or(Decimal::zero());
+
+            let balance = self.treasury_balance(stable_asset);
+
+            let available = if balance > earmarked { balance - earmarked } else { Decimal::zero() };
+
+            let pay_now = amount.min(available);
+
+            if pay_now > Decimal::zero() {
+                let payout = self.withdraw_from_treasury(stable_asset, pay_now);
+
+                let mut claimant_account: Global<Account> = Global::from(claimant);
+                claimant_account.try_deposit_or_abort(payout, None);
+            }
+
+            let shortfall = amount - pay_now;
+
+            if shortfall > Decimal::zero() {
+                self.slash_stakers(shortfall, claimant);
+            }
+        }
+
+        // pays a policy claim from its own pool; only a shortfall beyond that pool's capital reaches the stakers
+        fn pay_policy_claim(
+            &mut self,
+            pool_name: &String,
+            policy_id: NonFungibleLocalId,
+            loss_amount: Decimal,
+            incident_category: String,
+            incident_time: i64,
+        ) {
+            let mut pool = self.get_risk_pool(pool_name);
+
+            let (beneficiary, payout, breakdown) =
+                pool.pay_claim(policy_id.clone(), loss_amount, incident_category.clone(), incident_time);
+
+            let amount = breakdown.payout;
+
+            self.charge_budget(BudgetCategory::Claims, self.stable_asset, amount);
+
+            self.aggregate_claims_paid += amount;
+
+            let paid = payout.amount();
+
+            if paid > Decimal::zero() {
+                let mut beneficiary_account: Global<Account> = Global::from(beneficiary);
+                beneficiary_account.try_deposit_or_abort(payout, None);
+            } else {
+                payout.drop_empty();
+            }
+
+            let event_metadata = PolicyClaimPaid {
+                pool_name: pool_name.clone(),
+                policy_id,
+                claimant: beneficiary,
+                incident_category,
+                amount_claimed: amount,
+                amount_paid: paid,
+                breakdown,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::POLICY_CLAIM_PAID,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::PolicyClaimPaid(event_metadata),
+            });
+
+            let shortfall = amount - paid;
+
+            if shortfall > Decimal::zero() {
+                self.slash_stakers(shortfall, beneficiary);
+            }
+        }
+
+        // parametric policies are paid without a vote once the pool's oracle condition held during cover
+        pub fn settle_parametric_claim(&mut self, pool_name: String, policy: Proof, account: Global<Account>) {
+            let mut pool = self.get_risk_pool(&pool_name);
+
+            let policy_id = self.claimant_policy_id(&pool_name, policy, &account);
+
+            let policy_data = pool.get_policy(policy_id.clone());
+            let remaining_coverage = policy_data.coverage_amount - policy_data.claimed_amount;
+
+            assert!(remaining_coverage > Decimal::zero(), "this policy has already been paid out");
+
+            let trigger = pool.record_parametric_settlement(policy_id.clone());
+
+            self.pay_policy_claim(
+                &pool_name,
+                policy_id.clone(),
+                remaining_coverage,
+                String::from("parametric"),
+                trigger.observed_at,
+            );
+
+            let event_metadata = ParametricClaimSettled {
+                pool_name,
+                policy_id,
+                trigger_value: trigger.value,
+                observed_at: trigger.observed_at,
+                amount_claimed: remaining_coverage,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::PARAMETRIC_CLAIM_SETTLED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ParametricClaimSettled(event_metadata),
+            });
+        }
+
+        // the account presenting the policy becomes its holder, so payouts follow transferred policies
+        fn claimant_policy_id(&self, pool_name: &String, policy: Proof, account: &Global<Account>) -> NonFungibleLocalId {
+            let owner_role_of_claimant = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_claimant.rule);
+
+            let mut pool = self.get_risk_pool(pool_name);
+
+            let policy_id = policy
+                .check_with_message(pool.get_pool_info().policy_resource, "this is not a policy of the given pool")
+                .as_non_fungible()
+                .non_fungible_local_id();
+
+            assert!(
+                pool.get_policy(policy_id.clone()).superseded_by.is_none(),
+                "this policy was reissued to a new holder"
+            );
+
+            pool.record_holder(policy_id.clone(), account.address());
+
+            policy_id
+        }
+
+        fn get_risk_pool(&self, name: &String) -> Global<InsurancePool> {
+            self.risk_pools
+                .get(name)
+                .cloned()
+                .expect("no risk pool with this name")
+        }
+
+        pub fn get_risk_pools(&self) -> HashMap<String, Global<InsurancePool>> {
+            self.risk_pools.clone()
+        }
+
+        pub fn get_risk_pool_info(&self, name: String) -> RiskPoolInfo {
+            self.get_risk_pool(&name).get_pool_info()
+        }
+
+        pub fn get_risk_pool_utilization(&self, name: String) -> Decimal {
+            self.get_risk_pool(&name).get_utilization()
+        }
+
+        pub fn get_policy_status(&self, pool_name: String, policy_id: NonFungibleLocalId) -> PolicyStatus {
+            self.get_risk_pool(&pool_name).get_policy_status(policy_id)
+        }
+
+        pub fn quote_premium(
+            &self,
+            pool_name: String,
+            coverage_amount: Decimal,
+            duration_seconds: i64,
+            risk_class: RiskClass,
+        ) -> PremiumQuote {
+            self.get_risk_pool(&pool_name).quote_premium(coverage_amount, duration_seconds, risk_class)
+        }
+
+        // Covers a claim shortfall from staked first-loss capital. Every staker loses governance tokens
+        // pro rata, including tokens still in their unstaking cooldown, and the DAO sells them for XRD
+        // on the claimant's behalf: along a bonding curve straight out of the curve reserve, at a fixed
+        // price through the sale vault, where the next sales settle the claimant's queued redemption
+        // before anyone else's. Whatever is not paid right away is queued as a redemption.
+        fn slash_stakers(&mut self, shortfall: Decimal, claimant: ComponentAddress) {
+            // staking is closed unless claims are paid in XRD, see `stake` and SetStableAsset
+            let total_at_risk = self.get_total_at_risk();
+
+            let value_at_risk = self.get_buy_back_value(total_at_risk);
+
+            if total_at_risk.is_zero() || value_at_risk.is_zero() {
+                self.enqueue_redemption(claimant, self.stable_asset, shortfall);
+                return;
+            }
+
+            let tokens_to_slash = total_at_risk * (shortfall / value_at_risk).min(Decimal::one());
+
+            let mut slashed_shares = Vec::new();
+            let mut total_slashed = Decimal::zero();
+
+            for (staker, position) in self.stakes.iter_mut() {
+                let at_risk = position.staked_amount + position.unstaking_amount;
+
+                if at_risk.is_zero() {
+                    continue;
+                }
+
+                let slashed = tokens_to_slash * at_risk / total_at_risk;
+
+                position.settle(&self.reward_per_token);
+
+                let from_stake = slashed.min(position.staked_amount);
+
+                position.staked_amount -= from_stake;
+                position.unstaking_amount -= slashed - from_stake;
+
+                self.total_staked -= from_stake;
+                total_slashed += slashed;
+
+                slashed_shares.push((*staker, slashed));
+            }
+
+            let slashed_tokens = self.staking_vault.take(total_slashed);
+
+            let (covered, value_slashed) = match &self.bonding_curve {
+                Some(_) => {
+                    let proceeds = self.get_buy_back_value(total_slashed);
+
+                    self.curve_supply -= total_slashed;
+                    self.dao_token.put(slashed_tokens);
+
+                    let mut payout = self.curve_reserve.take(proceeds);
+
+                    // the top of the curve can sell for more than the shortfall, the rest stays with the DAO
+                    if proceeds > shortfall {
+                        let excess = payout.take(proceeds - shortfall);
+
+                        self.record_treasury_inflow(XRD, excess.amount());
+                        self.deposit_to_treasury(excess);
+                    }
+
+                    let mut claimant_account: Global<Account> = Global::from(claimant);
+                    claimant_account.try_deposit_or_abort(payout, None);
+
+                    (proceeds.min(shortfall), proceeds.min(shortfall))
+                }
+                None => {
+                    self.dao_token.put(slashed_tokens);
+
+                    (Decimal::zero(), (total_slashed * self.token_price).min(shortfall))
+                }
+            };
+
+            if shortfall > covered {
+                let request_id = self.next_redemption_id;
+
+                self.enqueue_redemption(claimant, XRD, shortfall - covered);
+
+                if self.bonding_curve.is_none() {
+                    self.slashed_token_sales.push((request_id, total_slashed));
+                }
+            }
+
+            for (staker, slashed) in slashed_shares {
+                let event_metadata = StakeSlashed {
+                    staker,
+                    tokens_slashed: slashed,
+                    value_slashed: value_slashed * slashed / total_slashed,
+                    claimant,
+                };
+
+                Runtime::emit_event(PandaoEvent {
+                    event_type: EventType::STAKE_SLASHED,
+                    dao_type: DaoType::Insurance,
+                    component_address: Runtime::global_address(),
+                    meta_data: DaoEvent::StakeSlashed(event_metadata),
+                });
+            }
+        }
+
+        // staked tokens plus those still in their unstaking cooldown, all of which can be slashed
+        pub fn get_total_at_risk(&self) -> Decimal {
+            self.stakes
+                .values()
+                .fold(Decimal::zero(), |total, position| {
+                    total + position.staked_amount + position.unstaking_amount
+                })
+        }
+
+        fn assert_stable_asset_stakeable(&self, resource_address: ResourceAddress) {
+            assert!(
+                resource_address == XRD || self.get_total_at_risk().is_zero(),
+                "claims must stay in XRD while staked capital backs them"
+            );
+        }
+
+        // risk pools hand their premiums through here so stakers share in every line of cover
+        pub fn share_pool_premium(&mut self, pool_name: String, premium: Bucket) -> Bucket {
+            let pool = self.get_risk_pool(&pool_name);
+
+            Runtime::assert_access_rule(rule!(require(global_caller(pool.address()))));
+
+            self.share_with_stakers(premium)
+        }
+
+        // moves the stakers' cut of incoming premiums or payouts into the reward vaults
+        fn share_with_stakers(&mut self, mut income: Bucket) -> Bucket {
+            if self.total_staked.is_zero() || self.staking_reward_share.is_zero() {
+                return income;
+            }
+
+            let resource_address = income.resource_address();
+
+            let reward = income.take(income.amount() * self.staking_reward_share);
+
+            *self
+                .reward_per_token
+                .entry(resource_address)
+                .or_insert(Decimal::zero()) += reward.amount() / self.total_staked;
+
+            if self.staking_reward_vaults.get(&resource_address).is_some() {
+                self.staking_reward_vaults.get_mut(&resource_address).unwrap().put(reward);
+            } else {
+                self.staking_reward_vaults.insert(resource_address, Vault::with_bucket(reward));
+            }
+
+            income
+        }
+
+        pub fn register_assessor(&mut self, tokens: Bucket, account: Global<Account>) {
+            let owner_role_of_assessor = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_assessor.rule);
+
+            assert_eq!(
+                tokens.resource_address(),
+                self.dao_token_address,
+                "assessors stake the DAO governance token"
+            );
+
+            let assessor = account.address();
+            let amount = tokens.amount();
+
+            let stake = self.assessor_stakes.entry(assessor).or_insert(Decimal::zero());
+            *stake += amount;
+
+            assert!(
+                *stake >= self.minimum_assessor_stake,
+                "stake is below the minimum required to become an assessor"
+            );
+
+            if !self.assessor_list.contains(&assessor) {
+                self.assessor_list.push(assessor);
+                self.assessor_registered_at.insert(
+                    assessor,
+                    Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch,
+                );
+            }
+
+            self.assessor_vault.put(tokens);
+
+            self.emit_assessor_event(EventType::ASSESSOR_REGISTERED, assessor, amount);
+        }
+
+        // leaves the registry with the whole stake, only possible without open assignments
+        pub fn withdraw_assessor_stake(&mut self, account: Global<Account>) -> Bucket {
+            let owner_role_of_assessor = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_assessor.rule);
+
+            let assessor = account.address();
+
+            assert!(
+                !self
+                    .claim_reviews
+                    .values()
+                    .any(|review| review.assessors.contains(&assessor)),
+                "assessors can not leave while assigned to an open review"
+            );
+
+            let amount = self
+                .assessor_stakes
+                .remove(&assessor)
+                .expect("this account is not an assessor");
+
+            self.assessor_list.retain(|address| *address != assessor);
+            self.assessor_registered_at.remove(&assessor);
+
+            self.emit_assessor_event(EventType::ASSESSOR_WITHDRAWN, assessor, amount);
+
+            self.assessor_vault.take(amount)
+        }
+
+        // hands a policy claim to a committee of assessors drawn from the registry
+        pub fn request_claim_review(
+            &mut self,
+            pool_name: String,
+            policy: Proof,
+            amount: Decimal,
+            incident_category: String,
+            incident_time: i64,
+            mut fee: Bucket,
+            account: Global<Account>,
+        ) -> Bucket {
+            let pool = self.get_risk_pool(&pool_name);
+
+            let policy_id = self.claimant_policy_id(&pool_name, policy, &account);
+
+            pool.assert_claimable(policy_id.clone(), incident_time);
+
+            assert!(amount > Decimal::zero(), "claim amount must be positive");
+            assert!(fee.resource_address() == self.stable_asset, "the review fee must be paid in the stable asset");
+            assert!(fee.amount() >= self.review_fee, "insufficient review fee");
+
+            let claimant = account.address();
+
+            let review_id = self.next_review_id;
+            self.next_review_id += 1;
+
+            let fee_payment = fee.take(self.review_fee);
+
+            if self.review_fee_vaults.get(&self.stable_asset).is_some() {
+                self.review_fee_vaults.get_mut(&self.stable_asset).unwrap().put(fee_payment);
+            } else {
+                self.review_fee_vaults.insert(self.stable_asset, Vault::with_bucket(fee_payment));
+            }
+
+            let policy = pool.get_policy(policy_id.clone());
+
+            // Nobody reviews a claim that pays them. Assessors listed after the policy started are left
+            // out as well, so a claimant can not register a second account to sit on their own committee.
+            let candidates: Vec<(ComponentAddress, Decimal)> = self
+                .assessor_list
+                .iter()
+                .filter(|assessor| {
+                    **assessor != claimant && **assessor != policy.holder && **assessor != policy.beneficiary
+                })
+                .filter(|assessor| {
+                    self.assessor_registered_at
+                        .get(assessor)
+                        .map_or(false, |registered_at| *registered_at < policy.start_time)
+                })
+                .map(|assessor| (*assessor, self.assessor_stakes.get(assessor).cloned().unwrap_or(Decimal::zero())))
+                .collect();
+
+            // deterministic from the transaction hash, mixed with the review id so reviews in one transaction differ
+            let mut seed_bytes = Runtime::transaction_hash().0.to_vec();
+            seed_bytes.extend_from_slice(&review_id.to_le_bytes());
+
+            let committee = select_committee(&candidates, hash(seed_bytes), self.assessor_committee_size);
+
+            assert!(!committee.is_empty(), "there are no assessors to review this claim");
+
+            let review = ClaimReview {
+                review_id,
+                pool_name,
+                policy_id,
+                claimant,
+                amount,
+                incident_category,
+                incident_time,
+                assessors: committee.iter().map(|(assessor, _)| *assessor).collect(),
+                weights: committee.into_iter().collect(),
+                votes: HashMap::new(),
+                fee_resource: self.stable_asset,
+                fee: self.review_fee,
+                deadline: Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch + self.review_window_seconds,
+            };
+
+            self.claim_reviews.insert(review_id, review.clone());
+
+            self.track_open_policy_claim(&review.pool_name, review.amount, true);
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::CLAIM_REVIEW_OPENED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ClaimReviewOpened(review),
+            });
+
+            fee
+        }
+
+        pub fn assessor_vote(&mut self, review_id: u64, approve: bool, account: Global<Account>) {
+            let owner_role_of_assessor = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_assessor.rule);
+
+            let assessor = account.address();
+
+            let review = self
+                .claim_reviews
+                .get_mut(&review_id)
+                .expect("there is no open review with the given ID");
+
+            assert!(review.assessors.contains(&assessor), "you are not assigned to this review");
+            assert!(!review.votes.contains_key(&assessor), "You have already voted on this review.");
+            assert!(
+                Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch <= review.deadline,
+                "the review window has closed"
+            );
+
+            review.votes.insert(assessor, approve);
+        }
+
+        // pays the claim on a majority approval, rewards the majority and slashes the outliers
+        pub fn finalize_claim_review(&mut self, review_id: u64) {
+            let review = self
+                .claim_reviews
+                .get(&review_id)
+                .cloned()
+                .expect("there is no open review with the given ID");
+
+            assert!(
+                review.all_voted()
+                    || Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch > review.deadline,
+                "the review is still open"
+            );
+
+            self.claim_reviews.remove(&review_id);
+
+            self.track_open_policy_claim(&review.pool_name, review.amount, false);
+
+            let mut fee = self
+                .review_fee_vaults
+                .get_mut(&review.fee_resource)
+                .unwrap()
+                .take(review.fee);
+
+            let majority = review.majority();
+
+            let mut rewarded_assessors = Vec::new();
+            let mut slashed_assessors = HashMap::new();
+            let mut fee_per_assessor = Decimal::zero();
+
+            // drawn assessors who never voted lose stake like outliers do
+            for assessor in &review.assessors {
+                if !review.votes.contains_key(assessor) {
+                    if let Some(stake) = self.assessor_stakes.get_mut(assessor) {
+                        let slashed = *stake * self.assessor_slash_ratio;
+                        *stake -= slashed;
+                        slashed_assessors.insert(*assessor, slashed);
+                    }
+                }
+            }
+
+            match majority {
+                // nobody reviewed the claim, the claimant gets the fee back and may try again
+                None => {
+                    let mut claimant_account: Global<Account> = Global::from(review.claimant);
+                    claimant_account.try_deposit_or_abort(fee, None);
+                }
+                Some(approved) => {
+                    for (assessor, vote) in &review.votes {
+                        if *vote == approved {
+                            rewarded_assessors.push(*assessor);
+                        } else if let Some(stake) = self.assessor_stakes.get_mut(assessor) {
+                            let slashed = *stake * self.assessor_slash_ratio;
+                            *stake -= slashed;
+                            slashed_assessors.insert(*assessor, slashed);
+                        }
+                    }
+
+                    fee_per_assessor = review.fee / Decimal::from(rewarded_assessors.len() as u64);
+
+                    for assessor in &rewarded_assessors {
+                        let mut assessor_account: Global<Account> = Global::from(*assessor);
+                        assessor_account.try_deposit_or_abort(fee.take(fee_per_assessor), None);
+                    }
+
+                    // rounding dust stays with the DAO
+                    if fee.amount() > Decimal::zero() {
+                        self.record_treasury_inflow(review.fee_resource, fee.amount());
+                        self.deposit_to_treasury(fee);
+                    } else {
+                        fee.drop_empty();
+                    }
+
+                    if approved {
+                        self.pay_policy_claim(
+                            &review.pool_name,
+                            review.policy_id.clone(),
+                            review.amount,
+                            review.incident_category.clone(),
+                            review.incident_time,
+                        );
+                    }
+                }
+            }
+
+            let total_slashed = slashed_assessors
+                .values()
+                .fold(Decimal::zero(), |total, slashed| total + *slashed);
+
+            if total_slashed > Decimal::zero() {
+                self.dao_token.put(self.assessor_vault.take(total_slashed));
+            }
+
+            // assessors slashed below the minimum stake leave the registry, what is left stays withdrawable
+            for assessor in slashed_assessors.keys() {
+                let stake = self.assessor_stakes.get(assessor).cloned().unwrap_or(Decimal::zero());
+
+                if stake < self.minimum_assessor_stake && self.assessor_list.contains(assessor) {
+                    self.assessor_list.retain(|address| address != assessor);
+                    self.assessor_registered_at.remove(assessor);
+
+                    self.emit_assessor_event(EventType::ASSESSOR_DELISTED, *assessor, stake);
+                }
+            }
+
+            let event_metadata = ClaimReviewFinalized {
+                review_id,
+                approved: majority.unwrap_or(false),
+                amount: review.amount,
+                rewarded_assessors,
+                fee_per_assessor,
+                slashed_assessors,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::CLAIM_REVIEW_FINALIZED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ClaimReviewFinalized(event_metadata),
+            });
+        }
+
+        pub fn get_assessors(&self) -> HashMap<ComponentAddress, Decimal> {
+            self.assessor_stakes.clone()
+        }
+
+        pub fn get_claim_review(&self, review_id: u64) -> Option<ClaimReview> {
+            self.claim_reviews.get(&review_id).cloned()
+        }
+
+        fn offer_reinsurance(
+            &mut self,
+            ceding_dao: ComponentAddress,
+            premium: Decimal,
+            attachment_point: Decimal,
+            limit: Decimal,
+            duration_seconds: i64,
+        ) {
+            let treaty = ReinsuranceTreaty {
+                treaty_id: self.next_treaty_id,
+                reinsurer: Runtime::global_address(),
+                ceding_dao,
+                premium_resource: self.stable_asset,
+                premium,
+                attachment_point,
+                limit,
+                duration_seconds,
+                bound: false,
+                start_time: 0,
+                end_time: 0,
+                claims_at_inception: Decimal::zero(),
+                drawn: Decimal::zero(),
+            };
+
+            self.next_treaty_id += 1;
+
+            self.written_treaties.insert(treaty.treaty_id, treaty.clone());
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::REINSURANCE_OFFERED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ReinsuranceTreatyUpdated(treaty),
+            });
+        }
+
+        // Treaties are only offered to DAOs of this blueprint, so the claim totals reported by the ceding
+        // DAO come from its own `aggregate_claims_paid`. Reading them back from here is not an option,
+        // the ceding DAO is still mid-call when it binds or draws.
+        fn is_dao_instance(address: ComponentAddress) -> bool {
+            ScryptoVmV1Api::object_get_blueprint_id(address.as_node_id()) == TokenWeigtedDao::blueprint_id()
+        }
+
+        // called by the ceding DAO when its members approved buying an offered treaty
+        pub fn bind_reinsurance(&mut self, treaty_id: u64, mut premium: Bucket, claims_paid_to_date: Decimal) -> Bucket {
+            let offered = self.get_written_treaty(treaty_id);
+
+            Runtime::assert_access_rule(rule!(require(global_caller(offered.ceding_dao))));
+
+            assert!(!offered.bound, "this treaty is already bound");
+            assert!(
+                premium.resource_address() == offered.premium_resource,
+                "the premium must be paid in the treaty's premium resource"
+            );
+            assert!(premium.amount() >= offered.premium, "you are paying an insufficient premium");
+
+            // the limit is reserved for as long as the treaty is in force, it has to be there to begin with
+            assert!(
+                self.get_free_balance(offered.premium_resource) + offered.premium >= offered.limit,
+                "the reinsurer can not reserve this treaty's limit"
+            );
+
+            let treaty = self.written_treaties.get_mut(&treaty_id).unwrap();
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            treaty.bound = true;
+            treaty.start_time = now;
+            treaty.end_time = now + treaty.duration_seconds;
+            treaty.claims_at_inception = claims_paid_to_date;
+
+            let treaty = treaty.clone();
+
+            self.record_treasury_inflow(treaty.premium_resource, treaty.premium);
+            self.deposit_to_treasury(premium.take(treaty.premium));
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::REINSURANCE_BOUND,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ReinsuranceTreatyUpdated(treaty),
+            });
+
+            premium
+        }
+
+        // pays the ceding DAO what its claims above the attachment point entitle it to, up to the limit
+        pub fn draw_reinsurance(&mut self, treaty_id: u64, aggregate_claims_paid: Decimal) -> Bucket {
+            let treaty = self
+                .written_treaties
+                .get(&treaty_id)
+                .cloned()
+                .expect("there is no reinsurance treaty with the given ID");
+
+            Runtime::assert_access_rule(rule!(require(global_caller(treaty.ceding_dao))));
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            assert!(treaty.in_force(now), "this treaty is not in force");
+
+            let amount = treaty
+                .recoverable(aggregate_claims_paid)
+                .min(self.treasury_balance(treaty.premium_resource));
+
+            if amount.is_zero() {
+                return Bucket::new(treaty.premium_resource);
+            }
+
+            let recovery = self.withdraw_from_treasury(treaty.premium_resource, amount);
+
+            let treaty = self.written_treaties.get_mut(&treaty_id).unwrap();
+            treaty.drawn += amount;
+
+            let event_metadata = ReinsuranceDrawn {
+                treaty_id,
+                reinsurer: treaty.reinsurer,
+                ceding_dao: treaty.ceding_dao,
+                amount,
+                total_drawn: treaty.drawn,
+            };
 
-        //     // Get bond details of the latest bond component
-        //     latest_bond_component.get_bond_details()
-        // }
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::REINSURANCE_DRAWN,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ReinsuranceDrawn(event_metadata),
+            });
 
-        // Function to retrieve bond creators and their bond component addresses
-        pub fn get_bond_creators(&self) -> HashMap<ComponentAddress, Vec<Global<Annuity>>> {
-            self.ann_token.clone() // Return the HashMap of bond creators and their bonds
+            recovery
         }
 
-        // New function to get all bond creator addresses
-        pub fn get_bond_creator_addresses(&self) -> Vec<ComponentAddress> {
-            self.ann_token.keys().cloned().collect() // Return a list of bond creator addresses
-        }
+        // draws on every treaty in force and uses the recoveries to settle queued claims
+        pub fn recover_from_reinsurance(&mut self) {
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
 
-        // Function to get bond creator address and bond details
-        // pub fn get_bond_creator_and_details(&self) -> Vec<(ComponentAddress, Vec<BondDetails>)> {
-        //     let mut result = Vec::new();
+            for (reinsurer, treaty_id) in self.reinsurance_cover.clone() {
+                let mut reinsurer_dao: Global<TokenWeigtedDao> = Global::from(reinsurer);
 
-        //     // Iterate through each bond creator address and their bond components
-        //     for (creator_address, bonds) in &self.ann_token {
-        //         let mut bond_details = Vec::new();
-        //         for bond in bonds {
-        //             bond_details.push(bond.get_bond_details());
-        //         }
-        //         // Push the creator address and corresponding bond details to the result
-        //         result.push((*creator_address, bond_details));
-        //     }
+                let treaty = reinsurer_dao.get_written_treaty(treaty_id);
 
-        //     result
-        // }
+                if !treaty.in_force(now) || treaty.recoverable(self.aggregate_claims_paid).is_zero() {
+                    continue;
+                }
 
-        pub fn send_money_to_dao_treasury(
-            &mut self,
-            payment: Bucket,
-            account: Global<Account>,
-        ) -> Bucket {
-            // Ensure the payment is in XRD
-            assert_eq!(
-                payment.resource_address(),
-                XRD,
-                "Only XRD tokens are accepted for treasury contributions"
-            );
+                let recovery = reinsurer_dao.draw_reinsurance(treaty_id, self.aggregate_claims_paid);
 
-            // Get the amount being sent
-            let amount = payment.amount();
+                if recovery.amount() > Decimal::zero() {
+                    self.record_treasury_inflow(recovery.resource_address(), recovery.amount());
+                    self.deposit_to_treasury(recovery);
+                } else {
+                    recovery.drop_empty();
+                }
+            }
 
-            // Get the sender's address
-            let sender_address = account.address();
+            self.process_redemption_queue();
+        }
 
-            // Store the payment in the dao_token vault
-            self.dao_token.put(payment);
+        pub fn get_written_treaty(&self, treaty_id: u64) -> ReinsuranceTreaty {
+            self.written_treaties
+                .get(&treaty_id)
+                .cloned()
+                .expect("there is no reinsurance treaty with the given ID")
+        }
 
-            // Update the contributor's record
-            self.update_contributor_record(sender_address, amount);
+        pub fn get_written_treaties(&self) -> HashMap<u64, ReinsuranceTreaty> {
+            self.written_treaties.clone()
+        }
 
-            // Emit an event for the contribution
-            self.emit_contribution_event(sender_address, amount);
+        pub fn get_reinsurance_cover(&self) -> Vec<(ComponentAddress, u64)> {
+            self.reinsurance_cover.clone()
+        }
 
-            // Return an empty bucket
-            Bucket::new(XRD)
+        pub fn get_aggregate_claims_paid(&self) -> Decimal {
+            self.aggregate_claims_paid
         }
 
-        // Helper method to update the contributor's record
-        fn update_contributor_record(&mut self, address: ComponentAddress, amount: Decimal) {
-            *self.contributors.entry(address).or_insert(Decimal::zero()) += amount;
+        fn emit_assessor_event(&self, event_type: EventType, assessor: ComponentAddress, amount: Decimal) {
+            let event_metadata = StakeChanged {
+                staker: assessor,
+                amount,
+                total_staked: self.assessor_vault.amount(),
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::AssessorStakeChanged(event_metadata),
+            });
         }
 
-        // Helper method to emit a contribution event
-        fn emit_contribution_event(&self, address: ComponentAddress, amount: Decimal) {
-            let event_metadata = TreasuryContribution {
-                contributor: address,
-                amount: amount,
-                timestamp: Runtime::current_epoch().number(),
+        fn emit_staking_event(&self, event_type: EventType, staker: ComponentAddress, amount: Decimal) {
+            let event_metadata = StakeChanged {
+                staker,
+                amount,
+                total_staked: self.total_staked,
             };
 
             Runtime::emit_event(PandaoEvent {
-                event_type: EventType::TREASURY_CONTRIBUTION,
+                event_type,
                 dao_type: DaoType::Insurance,
                 component_address: Runtime::global_address(),
-                meta_data: DaoEvent::TreasuryContribution(event_metadata),
+                meta_data: DaoEvent::StakeChanged(event_metadata),
             });
         }
 
-        // Method to get all contributors and their total contributions
-        pub fn get_all_contributors(&self) -> HashMap<ComponentAddress, Decimal> {
-            self.contributors.clone()
+        pub fn get_whitelisted_assets(&self) -> HashSet<ResourceAddress> {
+            self.whitelisted_assets.clone()
+        }
+
+        pub fn get_swap_pools(&self) -> HashSet<ComponentAddress> {
+            self.swap_pools.clone()
+        }
+
+        pub fn get_stable_asset(&self) -> ResourceAddress {
+            self.stable_asset
+        }
+
+        pub fn treasury_balance(&self, resource_address: ResourceAddress) -> Decimal {
+            if resource_address == XRD {
+                return self.shares.amount();
+            }
+
+            match self.treasury.get(&resource_address) {
+                Some(vault) => vault.amount(),
+                None => Decimal::zero(),
+            }
+        }
+
+        fn deposit_to_treasury(&mut self, payment: Bucket) {
+            let resource_address = payment.resource_address();
+
+            assert!(
+                self.whitelisted_assets.contains(&resource_address),
+                "this resource is not whitelisted for the treasury"
+            );
+
+            if resource_address == XRD {
+                self.shares.put(payment);
+                return;
+            }
+
+            if self.treasury.get(&resource_address).is_some() {
+                self.treasury.get_mut(&resource_address).unwrap().put(payment);
+            } else {
+                self.treasury.insert(resource_address, Vault::with_bucket(payment));
+            }
+        }
+
+        fn withdraw_from_treasury(&mut self, resource_address: ResourceAddress, amount: Decimal) -> Bucket {
+            assert!(
+                self.treasury_balance(resource_address) >= amount,
+                "Insufficient funds in the treasury to execute the proposal."
+            );
+
+            self.record_treasury_outflow(resource_address, amount);
+
+            if resource_address == XRD {
+                return self.shares.take(amount);
+            }
+
+            self.treasury.get_mut(&resource_address).unwrap().take(amount)
+        }
+
+        // spending and investing may only draw on what the reserves leave free
+        fn withdraw_unreserved(&mut self, resource_address: ResourceAddress, amount: Decimal) -> Bucket {
+            assert!(
+                amount <= self.get_free_balance(resource_address),
+                "only funds above the treasury reserves can be used"
+            );
+
+            self.withdraw_from_treasury(resource_address, amount)
         }
 
         // pub fn execute_proposal_for_pandao(&mut self){
@@ -1264,49 +3969,300 @@ mod radixdao {
         //     )
         // }
 
-        pub fn mint_more_tokens(&mut self, token_number_to_mint: usize) {
-            self.dao_token
-                .put(self.dao_token_resource_manager.mint(token_number_to_mint));
+        // mints the allocations into the vesting vault and sends each beneficiary a receipt NFT
+        fn create_vesting_allocations(&mut self, allocations: Vec<VestingAllocation>) {
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            for allocation in allocations {
+                // vested tokens can be sold back along the curve like bought ones, so the treasury
+                // funds their share of the reserve as if it had bought them
+                if let Some(curve) = self.bonding_curve.clone() {
+                    let reserve_share =
+                        curve.purchase_cost(self.curve_supply, allocation.amount) * curve.reserve_ratio;
+
+                    let funding = self.withdraw_unreserved(XRD, reserve_share);
+
+                    self.curve_reserve.put(funding);
+                    self.curve_supply += allocation.amount;
+                }
+
+                self.vesting_vault
+                    .put(self.dao_token_resource_manager.mint(allocation.amount));
+
+                let receipt_data = VestingReceipt {
+                    beneficiary: allocation.beneficiary,
+                    total_amount: allocation.amount,
+                    start_time: now,
+                    cliff_time: now + allocation.cliff_seconds,
+                    end_time: now + allocation.vesting_seconds,
+                    claimed_amount: Decimal::zero(),
+                };
+
+                let receipt_id = NonFungibleLocalId::integer(self.next_vesting_id);
+
+                self.next_vesting_id += 1;
+
+                let receipt = self
+                    .vesting_receipt_manager
+                    .mint_non_fungible(&receipt_id, receipt_data.clone());
+
+                let mut beneficiary_account: Global<Account> = Global::from(allocation.beneficiary);
+                beneficiary_account.try_deposit_or_abort(receipt.into(), None);
+
+                Runtime::emit_event(PandaoEvent {
+                    event_type: EventType::VESTING_CREATED,
+                    dao_type: DaoType::Insurance,
+                    component_address: Runtime::global_address(),
+                    meta_data: DaoEvent::VestingCreated(receipt_data),
+                });
+            }
         }
 
-        pub fn execute_proposal_to_mint_more_tokens(
+        pub fn claim_vested_tokens(&mut self, vesting_receipt: Bucket) -> (Bucket, Bucket) {
+            assert_eq!(
+                vesting_receipt.resource_address(),
+                self.vesting_receipt_manager.address(),
+                "wrong vesting receipt supplied"
+            );
+
+            let receipt_id = vesting_receipt.as_non_fungible().non_fungible_local_id();
+
+            let receipt_data: VestingReceipt = self
+                .vesting_receipt_manager
+                .get_non_fungible_data(&receipt_id);
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let claimable = receipt_data.claimable_amount(now);
+
+            assert!(claimable > Decimal::zero(), "nothing has vested since the last claim");
+
+            let claimed_amount = receipt_data.claimed_amount + claimable;
+
+            self.vesting_receipt_manager
+                .update_non_fungible_data(&receipt_id, "claimed_amount", claimed_amount);
+
+            let event_metadata = VestedTokensClaimed {
+                receipt_id: receipt_id.clone(),
+                beneficiary: receipt_data.beneficiary,
+                amount: claimable,
+                claimed_amount,
+                total_amount: receipt_data.total_amount,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::VESTED_TOKENS_CLAIMED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::VestedTokensClaimed(event_metadata),
+            });
+
+            (vesting_receipt, self.vesting_vault.take(claimable))
+        }
+
+        pub fn get_vesting_receipt(&self, receipt_id: NonFungibleLocalId) -> VestingReceipt {
+            self.vesting_receipt_manager.get_non_fungible_data(&receipt_id)
+        }
+
+        pub fn get_claimable_vested_amount(&self, receipt_id: NonFungibleLocalId) -> Decimal {
+            let receipt_data: VestingReceipt = self.vesting_receipt_manager.get_non_fungible_data(&receipt_id);
+
+            receipt_data.claimable_amount(Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch)
+        }
+
+        // swaps treasury assets through a whitelisted pool, aborting below the minimum output the proposal fixed
+        fn rebalance_treasury(
             &mut self,
-            proposal_id: usize,
-        ) -> Result<String, String> {
-            for (_, inner_map) in &self.current_praposals {
-                let proposal = inner_map.get(&proposal_id);
+            pool_address: ComponentAddress,
+            input_resource: ResourceAddress,
+            input_amount: Decimal,
+            minimum_output: Decimal,
+        ) {
+            assert!(self.swap_pools.contains(&pool_address), "only whitelisted swap pools can be used");
 
-                let now: Instant = Clock::current_time_rounded_to_seconds();
-                let current_time_seconds: i64 = now.seconds_since_unix_epoch;
+            let mut pool: Global<SwapPool> = Global::from(pool_address);
 
-                let last_time = proposal.unwrap().get_last_time();
-                let end_time_seconds = last_time.to_instant().seconds_since_unix_epoch;
+            let input = self.withdraw_unreserved(input_resource, input_amount);
 
-                // Debug statements to verify the times
-                println!("Current time (epoch seconds): {}", current_time_seconds);
-                println!("Proposal end time (epoch seconds): {}", end_time_seconds);
+            let output = pool.swap(input);
 
-                assert!(
-                    current_time_seconds > end_time_seconds,
-                    "Proposal can only be executed after the specified end time"
-                );
+            let output_resource = output.resource_address();
+            let output_amount = output.amount();
 
-                match proposal {
-                    Some(proposal) => {
-                        if let Some(how_much_amount) = proposal.get_token_mint_amount() {
-                            &self.mint_more_tokens(how_much_amount);
+            assert!(
+                output_amount >= minimum_output,
+                "swap output is below the minimum the proposal allows"
+            );
 
-                            let message = "proposal executed successfully".to_string();
+            self.deposit_to_treasury(output);
 
-                            return Ok(message);
-                        } else {
-                            return Err(format!("token_mint_amount is not present in a proposal with id : {proposal_id} and it seems not to be a proposal to mint more tokens"));
-                        }
-                    }
-                    None => return Err(format!("proposal with id : {proposal_id} not found")),
+            self.record_treasury_inflow(output_resource, output_amount);
+
+            let event_metadata = TreasuryRebalanced {
+                pool: pool_address,
+                input_resource,
+                input_amount,
+                output_resource,
+                output_amount,
+                minimum_output,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::TREASURY_REBALANCED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::TreasuryRebalanced(event_metadata),
+            });
+        }
+
+        fn charge_budget(&mut self, category: BudgetCategory, resource_address: ResourceAddress, amount: Decimal) {
+            if let Some(limit) = self.budgets.get_mut(&(category, resource_address)) {
+                limit.charge(amount);
+            }
+        }
+
+        pub fn get_remaining_budgets(&self) -> HashMap<(BudgetCategory, ResourceAddress), Decimal> {
+            self.budgets
+                .iter()
+                .map(|(key, limit)| (*key, limit.remaining()))
+                .collect()
+        }
+
+        pub fn get_budgets(&self) -> HashMap<(BudgetCategory, ResourceAddress), BudgetLimit> {
+            self.budgets.clone()
+        }
+
+        fn create_payment_stream(
+            &mut self,
+            recipient: ComponentAddress,
+            resource_address: ResourceAddress,
+            rate_per_second: Decimal,
+            start_time: i64,
+            end_time: i64,
+        ) {
+            let stream = PaymentStream {
+                stream_id: self.next_stream_id,
+                recipient,
+                resource_address,
+                rate_per_second,
+                start_time,
+                end_time,
+                withdrawn_amount: Decimal::zero(),
+                cancelled_at: None,
+            };
+
+            self.next_stream_id += 1;
+
+            let funding = self.withdraw_unreserved(resource_address, stream.total_amount());
+
+            self.stream_vaults.insert(stream.stream_id, Vault::with_bucket(funding));
+
+            self.payment_streams.insert(stream.stream_id, stream.clone());
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::PAYMENT_STREAM_CREATED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::PaymentStreamCreated(stream),
+            });
+        }
+
+        // stops the stream now; what has streamed stays withdrawable, the rest goes back to the treasury
+        fn cancel_payment_stream(&mut self, stream_id: u64) {
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let stream = self
+                .payment_streams
+                .get_mut(&stream_id)
+                .expect("no payment stream with the given ID");
+
+            assert!(stream.cancelled_at.is_none(), "this payment stream is already cancelled");
+
+            stream.cancelled_at = Some(now);
+
+            let unstreamed_amount = stream.total_amount() - stream.streamed_amount(now);
+            let resource_address = stream.resource_address;
+            let stream_snapshot = stream.clone();
+
+            let refund = self
+                .stream_vaults
+                .get_mut(&stream_id)
+                .unwrap()
+                .take(unstreamed_amount);
+
+            self.deposit_to_treasury(refund);
+
+            self.record_treasury_inflow(resource_address, unstreamed_amount);
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::PAYMENT_STREAM_CANCELLED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::PaymentStreamCancelled(stream_snapshot),
+            });
+        }
+
+        pub fn withdraw_from_stream(&mut self, stream_id: u64, account: Global<Account>) -> Bucket {
+            let owner_role_of_recipient = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_recipient.rule);
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+
+            let stream = self
+                .payment_streams
+                .get_mut(&stream_id)
+                .expect("no payment stream with the given ID");
+
+            assert_eq!(
+                stream.recipient,
+                account.address(),
+                "only the stream recipient can withdraw from it"
+            );
+
+            let withdrawable = stream.withdrawable_amount(now);
+
+            assert!(withdrawable > Decimal::zero(), "nothing has streamed since the last withdrawal");
+
+            stream.withdrawn_amount += withdrawable;
+
+            self.stream_vaults
+                .get_mut(&stream_id)
+                .unwrap()
+                .take(withdrawable)
+        }
+
+        pub fn get_payment_streams(&self) -> HashMap<u64, PaymentStream> {
+            self.payment_streams.clone()
+        }
+
+        pub fn get_stream_withdrawable_amount(&self, stream_id: u64) -> Decimal {
+            let stream = self
+                .payment_streams
+                .get(&stream_id)
+                .expect("no payment stream with the given ID");
+
+            stream.withdrawable_amount(Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch)
+        }
+
+        // mint proposals only ever vest to their beneficiaries, never into the sale vault
+        pub fn execute_proposal_to_mint_more_tokens(
+            &mut self,
+            proposal_id: usize,
+        ) -> Result<String, String> {
+            let proposal = match self.find_proposal(proposal_id) {
+                Some(proposal) => proposal,
+                None => return Err(format!("proposal with id : {proposal_id} not found")),
+            };
+
+            match proposal.get_action() {
+                Some(ProposalAction::MintVested { .. }) => {
+                    // checks the vote outcome before minting
+                    self.execute_action_proposal(proposal_id);
+
+                    Ok("proposal executed successfully".to_string())
                 }
+                _ => Err(format!("proposal with id : {proposal_id} is not a proposal to mint more tokens")),
             }
-            return Err(format!("proposal with id : {proposal_id} not found"));
         }
 
         pub fn check_time_until_next_payout(
@@ -1371,8 +4327,50 @@ mod radixdao {
                 // let mut vault_to_give_back_ann = self.ann_tokens.get_mut(&r_a).unwrap();
                 vault.put(ann_token_in_return);
 
+                let payout = self.share_with_stakers(payout);
+
+                let payout_amount = payout.amount();
+
                 self.shares.put(payout);
 
+                self.record_treasury_inflow(XRD, payout_amount);
+
+                Ok(())
+            } else {
+                Err("No ANN Token created by the specified address.".to_string())
+            }
+        }
+
+        // the issuer buys back the DAO's ANN token early, so only the issuer's account may trigger it
+        // treasury assets are only given up through a passed SurrenderAnnuity proposal
+        fn surrender_ann_token(&mut self, ann_token_creator_address: ComponentAddress) -> Result<(), String> {
+            if let Some(ann_token_components) = self.ann_token.get_mut(&ann_token_creator_address) {
+
+                let latest_ann_component = ann_token_components
+                    .last_mut()
+                    .ok_or_else(|| "No ANN token component found".to_string())?;
+
+                let r_a = latest_ann_component.get_annuity_address();
+
+                let vault = self
+                    .ann_tokens
+                    .get_mut(&r_a)
+                    .ok_or_else(|| "The DAO does not hold any ANN token of this issuer".to_string())?;
+
+                if vault.amount() < Decimal::one() {
+                    return Err("The DAO does not hold any ANN token of this issuer".to_string());
+                }
+
+                let annuity_token_to_surrender = vault.take(1);
+
+                let refund = latest_ann_component.surrender_annuity(annuity_token_to_surrender);
+
+                let refund_amount = refund.amount();
+
+                self.shares.put(refund);
+
+                self.record_treasury_inflow(XRD, refund_amount);
+
                 Ok(())
             } else {
                 Err("No ANN Token created by the specified address.".to_string())
@@ -0,0 +1,32 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub enum MembershipTier {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+impl MembershipTier {
+    // tiers follow cumulative XRD contributed to the treasury
+    pub fn for_contribution(cumulative_contribution: Decimal) -> Self {
+        if cumulative_contribution >= Decimal::from(10000) {
+            MembershipTier::Gold
+        } else if cumulative_contribution >= Decimal::from(1000) {
+            MembershipTier::Silver
+        } else {
+            MembershipTier::Bronze
+        }
+    }
+}
+
+// data carried by the membership badge NFT issued on a member's first contribution
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct MemberBadge {
+    pub member: ComponentAddress,
+    pub joined_at: u64,
+    #[mutable]
+    pub cumulative_contribution: Decimal,
+    #[mutable]
+    pub tier: MembershipTier,
+}
@@ -0,0 +1,110 @@
+use scrypto::prelude::*;
+use crate::events::*;
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct Observation {
+    pub value: Decimal,
+    pub observed_at: i64,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriggerDirection {
+    Above,
+    Below,
+}
+
+// a parametric policy pays out in full once the oracle reports a value past the threshold
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct ParametricTrigger {
+    pub oracle: ComponentAddress,
+    pub direction: TriggerDirection,
+    pub threshold: Decimal,
+}
+
+impl ParametricTrigger {
+    pub fn is_triggered_by(&self, value: Decimal) -> bool {
+        match self.direction {
+            TriggerDirection::Above => value >= self.threshold,
+            TriggerDirection::Below => value <= self.threshold,
+        }
+    }
+}
+
+#[blueprint]
+#[events(PandaoEvent)]
+mod oracle {
+
+    enable_method_auth! {
+        methods {
+            report_value => restrict_to: [OWNER];
+            get_latest_observation => PUBLIC;
+            get_observations_between => PUBLIC;
+        }
+    }
+
+    // local stand-in for an index feed (rainfall, price, ...), reported by whoever holds the reporter badge
+    struct Oracle {
+        description: String,
+        observations: Vec<Observation>,
+    }
+
+    impl Oracle {
+        pub fn instantiate_oracle(description: String) -> (Global<Oracle>, Bucket) {
+            let reporter_badge: Bucket = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .metadata(metadata!(
+                    init {
+                        "name" => format!("{} reporter badge", &description), locked;
+                    }
+                ))
+                .mint_initial_supply(1)
+                .into();
+
+            let component = Self {
+                description,
+                observations: Vec::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
+                reporter_badge.resource_address()
+            ))))
+            .globalize();
+
+            (component, reporter_badge)
+        }
+
+        pub fn report_value(&mut self, value: Decimal) {
+            let observation = Observation {
+                value,
+                observed_at: Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch,
+            };
+
+            self.observations.push(observation.clone());
+
+            let event_metadata = OracleValueReported {
+                description: self.description.clone(),
+                value: observation.value,
+                observed_at: observation.observed_at,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::ORACLE_VALUE_REPORTED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::OracleValueReported(event_metadata),
+            });
+        }
+
+        pub fn get_latest_observation(&self) -> Option<Observation> {
+            self.observations.last().cloned()
+        }
+
+        pub fn get_observations_between(&self, start_time: i64, end_time: i64) -> Vec<Observation> {
+            self.observations
+                .iter()
+                .filter(|observation| observation.observed_at >= start_time && observation.observed_at <= end_time)
+                .cloned()
+                .collect()
+        }
+    }
+}
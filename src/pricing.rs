@@ -0,0 +1,145 @@
+use scrypto::prelude::*;
+
+pub const SECONDS_IN_YEAR: i64 = 365 * 24 * 60 * 60;
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RiskClass {
+    Low,
+    Standard,
+    High,
+}
+
+impl RiskClass {
+    pub fn multiplier(&self) -> Decimal {
+        match self {
+            RiskClass::Low => dec!("0.75"),
+            RiskClass::Standard => dec!("1"),
+            RiskClass::High => dec!("1.5"),
+        }
+    }
+}
+
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct PremiumQuote {
+    pub base_premium: Decimal,
+    pub risk_loading: Decimal,
+    pub utilization_loading: Decimal,
+    pub loss_ratio_loading: Decimal,
+    pub premium: Decimal,
+}
+
+// base premium pro rata for the duration, scaled by the risk class, how full the pool is
+// and how the pool's claims have run against its premiums so far
+pub fn quote_premium(
+    base_rate: Decimal,
+    coverage_amount: Decimal,
+    duration_seconds: i64,
+    risk_class: RiskClass,
+    utilization: Decimal,
+    loss_ratio: Decimal,
+) -> PremiumQuote {
+    assert!(coverage_amount >= Decimal::zero(), "coverage amount can not be negative");
+    assert!(duration_seconds >= 0, "coverage duration can not be negative");
+
+    let base_premium =
+        coverage_amount * base_rate * Decimal::from(duration_seconds) / Decimal::from(SECONDS_IN_YEAR);
+
+    let risk_loading = risk_class.multiplier();
+    let utilization_loading = utilization_loading(utilization);
+    let loss_ratio_loading = loss_ratio_loading(loss_ratio);
+
+    PremiumQuote {
+        base_premium,
+        risk_loading,
+        utilization_loading,
+        loss_ratio_loading,
+        premium: base_premium * risk_loading * utilization_loading * loss_ratio_loading,
+    }
+}
+
+// 1 for an empty pool, rising quadratically to 2 for a full one
+pub fn utilization_loading(utilization: Decimal) -> Decimal {
+    let utilization = utilization.max(Decimal::zero()).min(Decimal::one());
+
+    Decimal::one() + utilization * utilization
+}
+
+// loss ratio the base premium rate is calibrated for; worse experience is loaded on top
+pub fn target_loss_ratio() -> Decimal {
+    dec!("0.6")
+}
+
+pub fn loss_ratio_loading(loss_ratio: Decimal) -> Decimal {
+    if loss_ratio <= target_loss_ratio() {
+        return Decimal::one();
+    }
+
+    loss_ratio / target_loss_ratio()
+}
+
+pub fn loss_ratio(total_claims_paid: Decimal, total_premiums: Decimal) -> Decimal {
+    if total_premiums == Decimal::zero() {
+        return Decimal::zero();
+    }
+
+    total_claims_paid / total_premiums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_year_standard_cover_in_an_empty_pool_costs_the_base_rate() {
+        let quote = quote_premium(dec!("0.05"), dec!("1000"), SECONDS_IN_YEAR, RiskClass::Standard, dec!("0"), dec!("0"));
+
+        assert_eq!(quote.base_premium, dec!("50"));
+        assert_eq!(quote.premium, dec!("50"));
+    }
+
+    #[test]
+    fn premium_is_pro_rata_to_duration() {
+        let quote = quote_premium(dec!("0.05"), dec!("1000"), SECONDS_IN_YEAR / 2, RiskClass::Standard, dec!("0"), dec!("0"));
+
+        assert_eq!(quote.premium, dec!("25"));
+    }
+
+    #[test]
+    fn risk_class_scales_the_premium() {
+        let low = quote_premium(dec!("0.1"), dec!("1000"), SECONDS_IN_YEAR, RiskClass::Low, dec!("0"), dec!("0"));
+        let high = quote_premium(dec!("0.1"), dec!("1000"), SECONDS_IN_YEAR, RiskClass::High, dec!("0"), dec!("0"));
+
+        assert_eq!(low.premium, dec!("75"));
+        assert_eq!(high.premium, dec!("150"));
+    }
+
+    #[test]
+    fn utilization_loading_is_capped_between_one_and_two() {
+        assert_eq!(utilization_loading(dec!("0")), dec!("1"));
+        assert_eq!(utilization_loading(dec!("0.5")), dec!("1.25"));
+        assert_eq!(utilization_loading(dec!("1")), dec!("2"));
+        assert_eq!(utilization_loading(dec!("3")), dec!("2"));
+        assert_eq!(utilization_loading(dec!("-1")), dec!("1"));
+    }
+
+    #[test]
+    fn only_loss_ratios_above_target_are_loaded() {
+        assert_eq!(loss_ratio_loading(dec!("0.3")), dec!("1"));
+        assert_eq!(loss_ratio_loading(target_loss_ratio()), dec!("1"));
+        assert_eq!(loss_ratio_loading(dec!("0.9")), dec!("1.5"));
+    }
+
+    #[test]
+    fn loss_ratio_of_a_pool_without_premiums_is_zero() {
+        assert_eq!(loss_ratio(dec!("10"), dec!("0")), dec!("0"));
+        assert_eq!(loss_ratio(dec!("30"), dec!("60")), dec!("0.5"));
+    }
+
+    #[test]
+    fn loadings_compound() {
+        let quote = quote_premium(dec!("0.1"), dec!("1000"), SECONDS_IN_YEAR, RiskClass::High, dec!("0.5"), dec!("1.2"));
+
+        // 100 * 1.5 * 1.25 * 2
+        assert_eq!(quote.premium, dec!("375"));
+    }
+}
@@ -4,7 +4,29 @@ use scrypto::prelude::*;
 mod pandao_praposal {
     use std::path::Component;
 
+    enable_method_auth! {
+        methods {
+            vote => restrict_to: [OWNER];
+            set_vote_caster_address => restrict_to: [OWNER];
+            release_votes => PUBLIC;
+            get_address_issued_bonds => PUBLIC;
+            get_target_xrd_amount => PUBLIC;
+            get_committed_xrd_amount => PUBLIC;
+            get_vote_caster_addresses => PUBLIC;
+            get_last_time => PUBLIC;
+            get_token_mint_amount => PUBLIC;
+            get_number_of_voters => PUBLIC;
+            get_minimum_quorum => PUBLIC;
+            get_action => PUBLIC;
+            get_voted_for => PUBLIC;
+            get_voted_against => PUBLIC;
+            get_linked_proposal => PUBLIC;
+            get_voting_type => PUBLIC;
+        }
+    }
+
     use crate::VotingType;
+    use crate::ProposalAction;
 
     pub struct TokenWeightProposal {
         /// A simple string representing the current proposal.
@@ -42,6 +64,13 @@ mod pandao_praposal {
         pub proposal_creator_address : Option<ComponentAddress>,
         pub amount_of_tokens_should_be_minted : Option<usize>,
         pub voting_type: VotingType,
+        // what the DAO does with its treasury once this proposal passes
+        pub action: Option<ProposalAction>,
+        // the rejected proposal this one appeals, if any
+        pub linked_proposal: Option<usize>,
+        // voting tokens are held here until voting ends
+        pub locked_votes: Vault,
+        pub vote_locks: HashMap<ComponentAddress, Decimal>,
         // pub number_of_people_voted: i32
     }
 
@@ -60,6 +89,9 @@ mod pandao_praposal {
             proposal_creator_address : Option<ComponentAddress>,
             amount_of_tokens_should_be_minted : Option<usize>,
             voting_type: VotingType, // New parameter
+            action: Option<ProposalAction>,
+            linked_proposal: Option<usize>,
+            dao_address: ComponentAddress,
         ) -> (Global<TokenWeightProposal >, GlobalAddressReservation) {
             
             let (address_reservation, _) =
@@ -80,17 +112,39 @@ mod pandao_praposal {
                 vote_caster_addresses : HashSet::new(),
                 proposal_creator_address,
                 amount_of_tokens_should_be_minted,
-                voting_type
+                voting_type,
+                action,
+                linked_proposal,
+                locked_votes: Vault::new(voter_badge_address),
+                vote_locks: HashMap::new(),
             }
             .instantiate()
-            .prepare_to_globalize(OwnerRole::None)
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(global_caller(dao_address)))))
             .with_address(address_reservation.clone())
             .globalize();
 
             (proposal, address_reservation)
         }
 
-        pub fn vote(&mut self, token: Bucket, against: bool) -> Bucket {
+        pub fn vote(&mut self, token: Bucket, against: bool, voter: ComponentAddress) {
+
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+            assert!(
+                now >= self.start_time.to_instant().seconds_since_unix_epoch
+                    && now < self.end_time.to_instant().seconds_since_unix_epoch,
+                "voting is not open for this proposal"
+            );
+
+            assert_eq!(
+                token.resource_address(),
+                self.voter_badge_address,
+                "wrong voting token supplied"
+            );
+
+            assert!(
+                !self.vote_caster_addresses.contains(&voter),
+                "You have already voted on this proposal."
+            );
 
             let mut amount : Decimal = Default::default();
 
@@ -103,32 +157,35 @@ mod pandao_praposal {
                 }
             }
 
-            assert_eq!(
-                token.resource_address(),
-                self.voter_badge_address,
-                "wrong voting token supplied"
-            );
-    
-
-            // let amount = token.amount();
-
             if against {
-                
                 self.voted_against += amount;
-                // self.number_of_people_voted +=1 ; 
-
-                token
-
             } else {
-
                 self.voted_for += amount;
-                // self.number_of_people_voted +=1 ; 
+            }
 
-                token
+            // the tokens stay locked so the same weight can not be voted again from another account
+            self.vote_locks.insert(voter, token.amount());
+            self.locked_votes.put(token);
+            self.vote_caster_addresses.insert(voter);
+        }
 
-            }
+        // hands a voter's locked tokens back once voting has ended
+        pub fn release_votes(&mut self, account: Global<Account>) -> Bucket {
+            let owner_role_of_voter = account.get_owner_role();
+            Runtime::assert_access_rule(owner_role_of_voter.rule);
 
-            
+            let now = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch;
+            assert!(
+                now >= self.end_time.to_instant().seconds_since_unix_epoch,
+                "votes are locked until voting ends"
+            );
+
+            let amount = self
+                .vote_locks
+                .remove(&account.address())
+                .expect("no locked votes for this account");
+
+            self.locked_votes.take(amount)
         }
 
         pub fn get_address_issued_bonds(&self) -> ComponentAddress {
@@ -149,6 +206,11 @@ mod pandao_praposal {
             }
         }
 
+        // XRD this proposal would spend if executed, zero for proposals that do not spend
+        pub fn get_committed_xrd_amount(&self) -> Decimal {
+            self.target_xrd_amount.unwrap_or(Decimal::zero())
+        }
+
         pub fn get_vote_caster_addresses(&self) -> HashSet<ComponentAddress> {
             self.vote_caster_addresses.clone()
         }
@@ -173,5 +235,25 @@ mod pandao_praposal {
             self.minimum_quorum
         }
 
+        pub fn get_action(&self) -> Option<ProposalAction> {
+            self.action.clone()
+        }
+
+        pub fn get_voted_for(&self) -> Decimal {
+            self.voted_for
+        }
+
+        pub fn get_voted_against(&self) -> Decimal {
+            self.voted_against
+        }
+
+        pub fn get_linked_proposal(&self) -> Option<usize> {
+            self.linked_proposal
+        }
+
+        pub fn get_voting_type(&self) -> VotingType {
+            self.voting_type.clone()
+        }
+
     }
 }
@@ -0,0 +1,85 @@
+use scrypto::prelude::*;
+use crate::events::*;
+
+#[blueprint]
+#[events(PandaoEvent)]
+mod rate_oracle {
+
+    enable_method_auth! {
+        methods {
+            update_rate => restrict_to: [OWNER];
+            get_rate => PUBLIC;
+            get_last_updated => PUBLIC;
+        }
+    }
+
+    // local stand-in for a reference rate feed, updated by whoever holds the admin badge
+    struct RateOracle {
+        description: String,
+        reference_rate: Decimal,
+        last_updated: u64,
+    }
+
+    impl RateOracle {
+        pub fn instantiate_rate_oracle(
+            description: String,
+            initial_rate: Decimal,
+        ) -> (Global<RateOracle>, Bucket) {
+            let admin_badge: Bucket = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .metadata(metadata!(
+                    init {
+                        "name" => format!("{} admin badge", &description), locked;
+                    }
+                ))
+                .mint_initial_supply(1)
+                .into();
+
+            let last_updated = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch as u64;
+
+            let component = Self {
+                description,
+                reference_rate: initial_rate,
+                last_updated,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
+                admin_badge.resource_address()
+            ))))
+            .globalize();
+
+            (component, admin_badge)
+        }
+
+        pub fn update_rate(&mut self, new_rate: Decimal) {
+            assert!(new_rate >= Decimal::zero(), "reference rate can not be negative");
+
+            let previous_rate = self.reference_rate;
+
+            self.reference_rate = new_rate;
+            self.last_updated = Clock::current_time_rounded_to_seconds().seconds_since_unix_epoch as u64;
+
+            let event_metadata = ReferenceRateUpdate {
+                description: self.description.clone(),
+                previous_rate,
+                new_rate,
+                updated_at: self.last_updated,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::REFERENCE_RATE_UPDATED,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::ReferenceRateUpdate(event_metadata),
+            });
+        }
+
+        pub fn get_rate(&self) -> Decimal {
+            self.reference_rate
+        }
+
+        pub fn get_last_updated(&self) -> u64 {
+            self.last_updated
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use scrypto::prelude::*;
+
+// excess-of-loss cover one DAO writes for another
+#[derive(ScryptoSbor, ScryptoEvent, Clone, Debug)]
+pub struct ReinsuranceTreaty {
+    pub treaty_id: u64,
+    pub reinsurer: ComponentAddress,
+    pub ceding_dao: ComponentAddress,
+    pub premium_resource: ResourceAddress,
+    pub premium: Decimal,
+    // aggregate claims the ceding DAO keeps for itself before the reinsurer pays
+    pub attachment_point: Decimal,
+    pub limit: Decimal,
+    pub duration_seconds: i64,
+    pub bound: bool,
+    pub start_time: i64,
+    pub end_time: i64,
+    // ceding DAO's lifetime claims when cover started, only later claims count
+    pub claims_at_inception: Decimal,
+    pub drawn: Decimal,
+}
+
+impl ReinsuranceTreaty {
+    pub fn in_force(&self, now: i64) -> bool {
+        self.bound && now >= self.start_time && now < self.end_time
+    }
+
+    // what the reinsurer still owes for the ceding DAO's claims since inception
+    pub fn recoverable(&self, aggregate_claims_paid: Decimal) -> Decimal {
+        let covered_claims = aggregate_claims_paid - self.claims_at_inception;
+
+        if covered_claims <= self.attachment_point {
+            return Decimal::zero();
+        }
+
+        let owed = (covered_claims - self.attachment_point).min(self.limit);
+
+        if owed > self.drawn {
+            owed - self.drawn
+        } else {
+            Decimal::zero()
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use scrypto::prelude::*;
+
+// a staker's governance tokens and their share of the reward-per-token accumulators
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct StakePosition {
+    pub staked_amount: Decimal,
+    pub unstaking_amount: Decimal,
+    pub unstake_available_at: i64,
+    // accumulator value per reward resource at the last settlement
+    pub reward_checkpoints: HashMap<ResourceAddress, Decimal>,
+    pub accrued_rewards: HashMap<ResourceAddress, Decimal>,
+}
+
+impl StakePosition {
+    pub fn new() -> Self {
+        Self {
+            staked_amount: Decimal::zero(),
+            unstaking_amount: Decimal::zero(),
+            unstake_available_at: 0,
+            reward_checkpoints: HashMap::new(),
+            accrued_rewards: HashMap::new(),
+        }
+    }
+
+    // credits rewards earned since the last checkpoint, must run before the staked amount changes
+    pub fn settle(&mut self, reward_per_token: &HashMap<ResourceAddress, Decimal>) {
+        for (resource_address, accumulator) in reward_per_token {
+            let checkpoint = self
+                .reward_checkpoints
+                .get(resource_address)
+                .cloned()
+                .unwrap_or(Decimal::zero());
+
+            let earned = self.staked_amount * (*accumulator - checkpoint);
+
+            if earned > Decimal::zero() {
+                *self
+                    .accrued_rewards
+                    .entry(*resource_address)
+                    .or_insert(Decimal::zero()) += earned;
+            }
+
+            self.reward_checkpoints.insert(*resource_address, *accumulator);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use scrypto::prelude::*;
+
+// a treasury payment released to the recipient second by second between start and end time
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct PaymentStream {
+    pub stream_id: u64,
+    pub recipient: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub rate_per_second: Decimal,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub withdrawn_amount: Decimal,
+    pub cancelled_at: Option<i64>,
+}
+
+impl PaymentStream {
+    pub fn total_amount(&self) -> Decimal {
+        self.rate_per_second * Decimal::from(self.end_time - self.start_time)
+    }
+
+    // everything released so far, frozen at the cancellation time for cancelled streams
+    pub fn streamed_amount(&self, now: i64) -> Decimal {
+        let mut until = now.min(self.end_time);
+
+        if let Some(cancelled_at) = self.cancelled_at {
+            until = until.min(cancelled_at);
+        }
+
+        if until <= self.start_time {
+            return Decimal::zero();
+        }
+
+        self.rate_per_second * Decimal::from(until - self.start_time)
+    }
+
+    pub fn withdrawable_amount(&self, now: i64) -> Decimal {
+        self.streamed_amount(now) - self.withdrawn_amount
+    }
+}
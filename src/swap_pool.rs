@@ -0,0 +1,176 @@
+use scrypto::prelude::*;
+use crate::events::*;
+
+#[blueprint]
+#[events(PandaoEvent)]
+mod swap_pool {
+
+    // minimal constant-product (x * y = k) pool for two fungible resources
+    struct SwapPool {
+        vault_a: Vault,
+        vault_b: Vault,
+        // fraction of every input kept in the pool for liquidity providers
+        fee: Decimal,
+        pool_units_manager: ResourceManager,
+    }
+
+    impl SwapPool {
+        pub fn instantiate_swap_pool(
+            bucket_a: Bucket,
+            bucket_b: Bucket,
+            fee: Decimal,
+        ) -> (Global<SwapPool>, Bucket) {
+            assert!(
+                bucket_a.resource_address() != bucket_b.resource_address(),
+                "a pool needs two different resources"
+            );
+            assert!(
+                !bucket_a.is_empty() && !bucket_b.is_empty(),
+                "initial liquidity must be provided for both resources"
+            );
+            assert!(
+                fee >= Decimal::zero() && fee < Decimal::one(),
+                "fee must be at least 0 and below 1"
+            );
+
+            let (address_reservation, component_address) =
+                Runtime::allocate_component_address(SwapPool::blueprint_id());
+
+            let pool_units: Bucket = ResourceBuilder::new_fungible(OwnerRole::None)
+                .metadata(metadata!(
+                    init {
+                        "name" => "Swap pool units", locked;
+                        "symbol" => "POOL", locked;
+                    }
+                ))
+                .mint_roles(mint_roles!(
+                    minter => rule!(require(global_caller(component_address)));
+                    minter_updater => rule!(deny_all);
+                ))
+                .burn_roles(burn_roles!(
+                    burner => rule!(require(global_caller(component_address)));
+                    burner_updater => rule!(deny_all);
+                ))
+                .mint_initial_supply(bucket_a.amount())
+                .into();
+
+            let component = Self {
+                pool_units_manager: pool_units.resource_manager(),
+                vault_a: Vault::with_bucket(bucket_a),
+                vault_b: Vault::with_bucket(bucket_b),
+                fee,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .with_address(address_reservation)
+            .globalize();
+
+            (component, pool_units)
+        }
+
+        pub fn swap(&mut self, input: Bucket) -> Bucket {
+            let input_resource = input.resource_address();
+            let input_amount = input.amount();
+
+            let output_amount = self.get_amount_out(input_resource, input_amount);
+
+            assert!(output_amount > Decimal::zero(), "swap amount is too small");
+
+            let output = if input_resource == self.vault_a.resource_address() {
+                self.vault_a.put(input);
+                self.vault_b.take(output_amount)
+            } else {
+                self.vault_b.put(input);
+                self.vault_a.take(output_amount)
+            };
+
+            let event_metadata = PoolSwap {
+                input_resource,
+                input_amount,
+                output_resource: output.resource_address(),
+                output_amount,
+            };
+
+            Runtime::emit_event(PandaoEvent {
+                event_type: EventType::POOL_SWAP,
+                dao_type: DaoType::Insurance,
+                component_address: Runtime::global_address(),
+                meta_data: DaoEvent::PoolSwap(event_metadata),
+            });
+
+            output
+        }
+
+        pub fn get_amount_out(&self, input_resource: ResourceAddress, input_amount: Decimal) -> Decimal {
+            let (input_reserve, output_reserve) = self.reserves_for(input_resource);
+
+            let input_after_fee = input_amount * (Decimal::one() - self.fee);
+
+            output_reserve * input_after_fee / (input_reserve + input_after_fee)
+        }
+
+        // units of the other resource one unit of `input_resource` is worth, before fees and price impact
+        pub fn get_spot_price(&self, input_resource: ResourceAddress) -> Decimal {
+            let (input_reserve, output_reserve) = self.reserves_for(input_resource);
+
+            output_reserve / input_reserve
+        }
+
+        pub fn get_reserves(&self) -> HashMap<ResourceAddress, Decimal> {
+            let mut reserves = HashMap::new();
+            reserves.insert(self.vault_a.resource_address(), self.vault_a.amount());
+            reserves.insert(self.vault_b.resource_address(), self.vault_b.amount());
+            reserves
+        }
+
+        // deposits at the current ratio and returns pool units plus whatever did not fit the ratio
+        pub fn add_liquidity(&mut self, mut bucket_a: Bucket, mut bucket_b: Bucket) -> (Bucket, Bucket, Bucket) {
+            if bucket_a.resource_address() != self.vault_a.resource_address() {
+                std::mem::swap(&mut bucket_a, &mut bucket_b);
+            }
+
+            assert_eq!(bucket_a.resource_address(), self.vault_a.resource_address(), "wrong resource supplied");
+            assert_eq!(bucket_b.resource_address(), self.vault_b.resource_address(), "wrong resource supplied");
+
+            let ratio_a = bucket_a.amount() / self.vault_a.amount();
+            let ratio_b = bucket_b.amount() / self.vault_b.amount();
+            let ratio = ratio_a.min(ratio_b);
+
+            self.vault_a.put(bucket_a.take(self.vault_a.amount() * ratio));
+            self.vault_b.put(bucket_b.take(self.vault_b.amount() * ratio));
+
+            let total_units = self.pool_units_manager.total_supply().unwrap();
+            let pool_units = self.pool_units_manager.mint(total_units * ratio);
+
+            (pool_units, bucket_a, bucket_b)
+        }
+
+        pub fn remove_liquidity(&mut self, pool_units: Bucket) -> (Bucket, Bucket) {
+            assert_eq!(
+                pool_units.resource_address(),
+                self.pool_units_manager.address(),
+                "wrong pool units supplied"
+            );
+
+            let total_units = self.pool_units_manager.total_supply().unwrap();
+            let share = pool_units.amount() / total_units;
+
+            let amount_a = self.vault_a.amount() * share;
+            let amount_b = self.vault_b.amount() * share;
+
+            pool_units.burn();
+
+            (self.vault_a.take(amount_a), self.vault_b.take(amount_b))
+        }
+
+        fn reserves_for(&self, input_resource: ResourceAddress) -> (Decimal, Decimal) {
+            if input_resource == self.vault_a.resource_address() {
+                (self.vault_a.amount(), self.vault_b.amount())
+            } else if input_resource == self.vault_b.resource_address() {
+                (self.vault_b.amount(), self.vault_a.amount())
+            } else {
+                panic!("this resource is not traded by the pool")
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use scrypto::prelude::*;
+
+// one beneficiary's share of a mint proposal; vesting starts when the proposal is executed
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug)]
+pub struct VestingAllocation {
+    pub beneficiary: ComponentAddress,
+    pub amount: Decimal,
+    pub cliff_seconds: i64,
+    pub vesting_seconds: i64,
+}
+
+// data carried by the vesting receipt NFT
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct VestingReceipt {
+    pub beneficiary: ComponentAddress,
+    pub total_amount: Decimal,
+    pub start_time: i64,
+    pub cliff_time: i64,
+    pub end_time: i64,
+    #[mutable]
+    pub claimed_amount: Decimal,
+}
+
+impl VestingReceipt {
+    // nothing before the cliff, then linear from the start time until the end time
+    pub fn vested_amount(&self, now: i64) -> Decimal {
+        if now < self.cliff_time {
+            return Decimal::zero();
+        }
+
+        if now >= self.end_time || self.end_time <= self.start_time {
+            return self.total_amount;
+        }
+
+        self.total_amount * Decimal::from(now - self.start_time) / Decimal::from(self.end_time - self.start_time)
+    }
+
+    pub fn claimable_amount(&self, now: i64) -> Decimal {
+        self.vested_amount(now) - self.claimed_amount
+    }
+}
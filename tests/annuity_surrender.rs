@@ -0,0 +1,103 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+
+// issues a five year annuity priced at 100 XRD with a 10% surrender penalty, returns its component
+fn issue_annuity(test: &mut TestLedger, dao: &Dao) -> ComponentAddress {
+    let now = test.now_seconds() as u64;
+    let issuer = test.account();
+
+    test.get(
+        dao.component,
+        "issue_ann_token",
+        manifest_args!(
+            "ANN".to_string(),
+            "RPA".to_string(),
+            "annuity-1".to_string(),
+            dec!("5"),
+            "XRD".to_string(),
+            now,
+            now + 5 * SECONDS_IN_YEAR as u64,
+            dec!("100"),
+            "long".to_string(),
+            dec!("100"),
+            dec!("10"),
+            issuer,
+            dec!("0.1"),
+            None::<actus::ann::RateResetTerms>
+        ),
+    )
+}
+
+fn purchase_annuity(test: &mut TestLedger, dao: &Dao) {
+    let account = test.account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!("100"))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(dao.component, "purchase_ann_token", |lookup| {
+            (account, lookup.bucket("payment"))
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    test.execute(manifest).expect_commit_success();
+}
+
+#[test]
+fn surrender_proposal_refunds_the_penalised_present_value_to_the_treasury() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("annuity");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let annuity = issue_annuity(&mut test, &dao);
+    purchase_annuity(&mut test, &dao);
+
+    let account = test.account();
+    let proposal_id = test.propose(&mut dao, ProposalAction::SurrenderAnnuity(account));
+
+    test.advance_time(60);
+
+    let surrender_value: Decimal = test.get(annuity, "get_surrender_value", manifest_args!());
+    assert!(surrender_value > Decimal::zero());
+
+    let treasury_before = test.xrd_balance(dao.component);
+
+    test.execute_action(&dao, proposal_id).expect_commit_success();
+
+    assert_eq!(test.xrd_balance(dao.component), treasury_before + surrender_value);
+}
+
+#[test]
+fn surrender_proposal_for_an_unknown_issuer_is_rejected() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("annuity");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let stranger = test.new_actor();
+
+    let receipt = test.try_propose(&dao, ProposalAction::SurrenderAnnuity(stranger.account));
+
+    expect_failure_containing(&receipt, "No ANN Token created by the specified address.");
+}
+
+#[test]
+fn surrender_fails_when_the_treasury_holds_no_annuity() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("annuity");
+    test.buy_tokens(&dao, dec!("100"));
+
+    issue_annuity(&mut test, &dao);
+
+    let account = test.account();
+    let receipt = test.pass_action(&mut dao, ProposalAction::SurrenderAnnuity(account));
+
+    expect_failure_containing(&receipt, "The DAO does not hold any ANN token of this issuer");
+}
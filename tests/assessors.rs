@@ -0,0 +1,151 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::assessors::ClaimReview;
+
+fn register_assessor(test: &mut TestLedger, dao: &Dao, actor: &Actor, stake: Decimal) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(actor.account, dao.token, stake)
+        .take_all_from_worktop(dao.token, "tokens")
+        .call_method_with_name_lookup(dao.component, "register_assessor", |lookup| {
+            (lookup.bucket("tokens"), actor.account)
+        })
+        .build();
+
+    test.execute_as(actor, manifest)
+}
+
+fn assessor(test: &mut TestLedger, dao: &Dao) -> Actor {
+    let actor = test.new_actor();
+
+    test.buy_tokens_as(&actor, dao, dec!("100"));
+    register_assessor(test, dao, &actor, dec!("100")).expect_commit_success();
+
+    actor
+}
+
+fn assessor_vote(test: &mut TestLedger, dao: &Dao, actor: &Actor, review_id: u64, approve: bool) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(dao.component, "assessor_vote", manifest_args!(review_id, approve, actor.account))
+        .build();
+
+    test.execute_as(actor, manifest)
+}
+
+// the default account claims on its policy, paying the 10 XRD review fee
+fn request_review(test: &mut TestLedger, dao: &Dao, amount: Decimal) -> TransactionReceipt {
+    let account = test.account();
+    let component = dao.component;
+    let incident_time = test.now_seconds();
+
+    test.call_with_policy(dao, "crop", 0, |builder| {
+        builder
+            .withdraw_from_account(account, XRD, dec!("10"))
+            .take_all_from_worktop(XRD, "fee")
+            .call_method_with_name_lookup(component, "request_claim_review", |lookup| {
+                (
+                    "crop".to_string(),
+                    lookup.proof("policy"),
+                    amount,
+                    "hack".to_string(),
+                    incident_time,
+                    lookup.bucket("fee"),
+                    account,
+                )
+            })
+    })
+}
+
+#[test]
+fn the_committee_majority_pays_the_claim_and_slashes_the_outlier() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("assessors");
+    test.buy_tokens(&dao, dec!("400"));
+
+    test.create_funded_pool(&mut dao, "crop", dec!("200"));
+
+    // only assessors registered before the policy started may review it
+    let assessors: Vec<Actor> = (0..3).map(|_| assessor(&mut test, &dao)).collect();
+
+    test.advance_time(60);
+
+    test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let account = test.account();
+    let account_before = test.xrd_balance(account);
+
+    request_review(&mut test, &dao, dec!("50")).expect_commit_success();
+
+    // with fewer candidates than seats every assessor is drawn
+    let review: Option<ClaimReview> = test.get(dao.component, "get_claim_review", manifest_args!(0u64));
+    assert_eq!(review.unwrap().assessors.len(), 3);
+
+    let balances_before: Vec<Decimal> = assessors.iter().map(|actor| test.xrd_balance(actor.account)).collect();
+
+    assessor_vote(&mut test, &dao, &assessors[0], 0, true).expect_commit_success();
+    assessor_vote(&mut test, &dao, &assessors[1], 0, true).expect_commit_success();
+
+    let receipt = test.call(dao.component, "finalize_claim_review", manifest_args!(0u64));
+    expect_failure_containing(&receipt, "the review is still open");
+
+    assessor_vote(&mut test, &dao, &assessors[2], 0, false).expect_commit_success();
+
+    test.call(dao.component, "finalize_claim_review", manifest_args!(0u64))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(account), account_before - dec!("10") + dec!("50"));
+
+    // the majority splits the fee, the outlier loses a tenth of their stake
+    assert_eq!(test.xrd_balance(assessors[0].account), balances_before[0] + dec!("5"));
+    assert_eq!(test.xrd_balance(assessors[1].account), balances_before[1] + dec!("5"));
+    assert_eq!(test.xrd_balance(assessors[2].account), balances_before[2]);
+
+    let stakes: HashMap<ComponentAddress, Decimal> = test.get(dao.component, "get_assessors", manifest_args!());
+    assert_eq!(stakes[&assessors[0].account], dec!("100"));
+    assert_eq!(stakes[&assessors[2].account], dec!("90"));
+}
+
+#[test]
+fn claimants_and_late_assessors_are_left_off_the_committee() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("assessors");
+    test.buy_tokens(&dao, dec!("400"));
+
+    test.create_funded_pool(&mut dao, "crop", dec!("200"));
+
+    let user = Actor {
+        public_key: test.user.public_key,
+        account: test.account(),
+    };
+    register_assessor(&mut test, &dao, &user, dec!("100")).expect_commit_success();
+
+    test.advance_time(60);
+
+    test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    assessor(&mut test, &dao);
+
+    let receipt = request_review(&mut test, &dao, dec!("50"));
+    expect_failure_containing(&receipt, "there are no assessors to review this claim");
+}
+
+#[test]
+fn assessors_must_stake_the_minimum() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("assessors");
+
+    let actor = test.new_actor();
+    test.buy_tokens_as(&actor, &dao, dec!("50"));
+
+    let receipt = register_assessor(&mut test, &dao, &actor, dec!("50"));
+    expect_failure_containing(&receipt, "stake is below the minimum required to become an assessor");
+}
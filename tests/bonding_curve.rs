@@ -0,0 +1,88 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::bonding_curve::{BondingCurve, BondingCurveShape};
+use actus::events::ProposalAction;
+
+// price starts at 1 XRD and rises 0.01 XRD per token sold, half of every purchase backs buy-backs
+fn linear_curve() -> BondingCurve {
+    BondingCurve {
+        shape: BondingCurveShape::Linear { slope: dec!("0.01") },
+        base_price: dec!("1"),
+        reserve_ratio: dec!("0.5"),
+    }
+}
+
+#[test]
+fn purchases_move_the_price_up_the_curve() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao_with_curve("curve", Some(linear_curve()));
+
+    let account = test.account();
+    let xrd_before = test.xrd_balance(account);
+
+    test.buy_tokens(&dao, dec!("100"));
+
+    // 100 tokens from a supply of 0: 100 * 1 + 0.01 * 100 * 100 / 2
+    assert_eq!(test.xrd_balance(account), xrd_before - dec!("150"));
+
+    let price: Decimal = test.get(dao.component, "get_current_token_price", manifest_args!());
+    assert_eq!(price, dec!("2"));
+
+    let reserve: Decimal = test.get(dao.component, "get_curve_reserve", manifest_args!());
+    assert_eq!(reserve, dec!("75"));
+
+    assert_eq!(test.xrd_balance(dao.component), dec!("150"));
+}
+
+#[test]
+fn buy_backs_are_paid_from_the_curve_reserve() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao_with_curve("curve", Some(linear_curve()));
+
+    test.buy_tokens(&dao, dec!("100"));
+
+    // a pending proposal does not hold up sales
+    test.propose(&mut dao, ProposalAction::SetClaimReserveRatio(dec!("0.5")));
+
+    let value: Decimal = test.get(dao.component, "get_buy_back_value", manifest_args!(dec!("50")));
+
+    // the last 50 tokens cost 50 * 1 + 0.01 * 50 * 150 / 2 = 87.5, half of that comes back
+    assert_eq!(value, dec!("43.75"));
+
+    let treasury_before = test.xrd_balance(dao.component);
+
+    test.sell_tokens(&dao, dec!("50")).expect_commit_success();
+
+    assert_eq!(test.xrd_balance(dao.component), treasury_before - value);
+
+    let reserve: Decimal = test.get(dao.component, "get_curve_reserve", manifest_args!());
+    assert_eq!(reserve, dec!("75") - value);
+
+    let price: Decimal = test.get(dao.component, "get_current_token_price", manifest_args!());
+    assert_eq!(price, dec!("1.5"));
+}
+
+#[test]
+fn invalid_curves_are_refused_at_instantiation() {
+    let mut test = TestLedger::new();
+
+    let mut curve = linear_curve();
+    curve.reserve_ratio = Decimal::zero();
+
+    let receipt = test.try_instantiate_dao("curve", Some(curve));
+    expect_failure_containing(&receipt, "reserve ratio must be greater than 0 and at most 1");
+
+    let curve = BondingCurve {
+        shape: BondingCurveShape::Linear { slope: dec!("-1") },
+        base_price: dec!("1"),
+        reserve_ratio: dec!("0.5"),
+    };
+
+    let receipt = test.try_instantiate_dao("curve", Some(curve));
+    expect_failure_containing(&receipt, "bonding curve slope can not be negative");
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::budget::{BudgetCategory, BudgetPeriod};
+use actus::events::ProposalAction;
+
+fn transfer(recipient: ComponentAddress, amount: Decimal) -> ProposalAction {
+    ProposalAction::TransferAsset {
+        resource_address: XRD,
+        amount,
+        recipient,
+    }
+}
+
+// operations may spend 100 XRD every 30 days
+fn budgeted_dao(test: &mut TestLedger) -> Dao {
+    let mut dao = test.instantiate_dao("budget");
+    test.buy_tokens(&dao, dec!("300"));
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::SetBudget {
+            category: BudgetCategory::Operations,
+            resource_address: XRD,
+            cap: dec!("100"),
+            period: BudgetPeriod::Monthly,
+        },
+    )
+    .expect_commit_success();
+
+    dao
+}
+
+fn remaining(test: &mut TestLedger, dao: &Dao, category: BudgetCategory) -> Decimal {
+    let budgets: HashMap<(BudgetCategory, ResourceAddress), Decimal> =
+        test.get(dao.component, "get_remaining_budgets", manifest_args!());
+
+    budgets[&(category, XRD)]
+}
+
+#[test]
+fn spending_is_charged_against_the_category_budget_until_the_period_resets() {
+    let mut test = TestLedger::new();
+
+    let mut dao = budgeted_dao(&mut test);
+    let recipient = test.new_actor();
+
+    test.pass_action(&mut dao, transfer(recipient.account, dec!("60")))
+        .expect_commit_success();
+
+    assert_eq!(remaining(&mut test, &dao, BudgetCategory::Operations), dec!("40"));
+
+    let receipt = test.pass_action(&mut dao, transfer(recipient.account, dec!("50")));
+    expect_failure_containing(&receipt, "this proposal exceeds the remaining budget for its category");
+
+    test.advance_time(30 * SECONDS_IN_DAY);
+
+    assert_eq!(remaining(&mut test, &dao, BudgetCategory::Operations), dec!("100"));
+
+    let recipient_before = test.xrd_balance(recipient.account);
+
+    test.pass_action(&mut dao, transfer(recipient.account, dec!("50")))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(recipient.account), recipient_before + dec!("50"));
+}
+
+#[test]
+fn budgets_only_limit_their_own_category() {
+    let mut test = TestLedger::new();
+
+    let mut dao = budgeted_dao(&mut test);
+    let claimant = test.new_actor();
+
+    let claimant_before = test.xrd_balance(claimant.account);
+
+    // claims are not capped, only operations are
+    test.pass_action(
+        &mut dao,
+        ProposalAction::PayClaim {
+            claimant: claimant.account,
+            amount: dec!("150"),
+        },
+    )
+    .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(claimant.account), claimant_before + dec!("150"));
+    assert_eq!(remaining(&mut test, &dao, BudgetCategory::Operations), dec!("100"));
+}
+
+#[test]
+fn removing_a_budget_lifts_its_cap() {
+    let mut test = TestLedger::new();
+
+    let mut dao = budgeted_dao(&mut test);
+    let recipient = test.new_actor();
+
+    test.pass_action(&mut dao, ProposalAction::RemoveBudget(BudgetCategory::Operations, XRD))
+        .expect_commit_success();
+
+    test.pass_action(&mut dao, transfer(recipient.account, dec!("150")))
+        .expect_commit_success();
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::{ProposalAction, RedemptionRequest};
+
+// 100 XRD in the treasury with half of it held back as the claim reserve
+fn reserved_dao(test: &mut TestLedger) -> Dao {
+    let mut dao = test.instantiate_dao("solvency");
+    test.buy_tokens(&dao, dec!("100"));
+
+    test.pass_action(&mut dao, ProposalAction::SetClaimReserveRatio(dec!("0.5")))
+        .expect_commit_success();
+
+    dao
+}
+
+fn claim_redemption(test: &mut TestLedger, dao: &Dao) -> TransactionReceipt {
+    let account = test.account();
+
+    test.call(dao.component, "claim_redemption", manifest_args!(account))
+}
+
+#[test]
+fn buy_backs_beyond_the_free_balance_are_queued() {
+    let mut test = TestLedger::new();
+
+    let dao = reserved_dao(&mut test);
+
+    let free: Decimal = test.get(dao.component, "get_free_balance", manifest_args!(XRD));
+    assert_eq!(free, dec!("50"));
+
+    test.sell_tokens(&dao, dec!("80")).expect_commit_success();
+
+    // only the free 50 XRD is paid out, the claim reserve stays in the treasury
+    assert_eq!(test.xrd_balance(dao.component), dec!("50"));
+
+    let queue: Vec<RedemptionRequest> = test.get(dao.component, "get_redemption_queue", manifest_args!());
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue[0].account, test.account());
+    assert_eq!(queue[0].amount_owed, dec!("30"));
+
+    // new money frees up enough to settle the rest
+    test.contribute(&dao, dec!("60")).expect_commit_success();
+
+    let queue: Vec<RedemptionRequest> = test.get(dao.component, "get_redemption_queue", manifest_args!());
+    assert!(queue.is_empty());
+
+    let account = test.account();
+    let xrd_before = test.xrd_balance(dao.component);
+
+    claim_redemption(&mut test, &dao).expect_commit_success();
+
+    assert_eq!(test.xrd_balance(dao.component), xrd_before - dec!("30"));
+
+    let claimable: HashMap<ResourceAddress, Decimal> =
+        test.get(dao.component, "get_claimable_redemptions", manifest_args!(account));
+    assert!(claimable.is_empty());
+}
+
+#[test]
+fn queued_redemptions_cannot_be_claimed_before_they_are_settled() {
+    let mut test = TestLedger::new();
+
+    let dao = reserved_dao(&mut test);
+
+    test.sell_tokens(&dao, dec!("80")).expect_commit_success();
+
+    let receipt = claim_redemption(&mut test, &dao);
+    expect_failure_containing(&receipt, "nothing to claim for this account");
+
+    assert_eq!(test.xrd_balance(dao.component), dec!("50"));
+}
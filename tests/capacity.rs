@@ -0,0 +1,78 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+
+// 100 XRD of capital backs at most 200 XRD of cover
+fn small_pool(test: &mut TestLedger) -> Dao {
+    let mut dao = test.instantiate_dao("capacity");
+    test.buy_tokens(&dao, dec!("300"));
+
+    test.create_funded_pool(&mut dao, "crop", dec!("100"));
+
+    dao
+}
+
+#[test]
+fn policies_beyond_the_pool_capacity_are_refused() {
+    let mut test = TestLedger::new();
+
+    let dao = small_pool(&mut test);
+
+    test.purchase_policy(&dao, "crop", dec!("150"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let info = test.pool_info(&dao, "crop");
+    assert_eq!(info.active_exposure, dec!("150"));
+    assert!(info.utilization > dec!("0.7") && info.utilization < dec!("0.75"));
+
+    let receipt = test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR);
+    expect_failure_containing(&receipt, "this pool has no capacity left for the requested coverage");
+
+    assert_eq!(test.pool_info(&dao, "crop").active_exposure, dec!("150"));
+}
+
+#[test]
+fn capital_backing_outstanding_cover_can_not_be_withdrawn() {
+    let mut test = TestLedger::new();
+
+    let mut dao = small_pool(&mut test);
+
+    test.purchase_policy(&dao, "crop", dec!("150"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let receipt = test.pass_action(
+        &mut dao,
+        ProposalAction::DefundRiskPool {
+            name: "crop".to_string(),
+            amount: dec!("50"),
+        },
+    );
+    expect_failure_containing(&receipt, "withdrawing this much would leave the pool's exposure uncovered");
+}
+
+#[test]
+fn lowering_the_coverage_cap_limits_new_policies() {
+    let mut test = TestLedger::new();
+
+    let mut dao = small_pool(&mut test);
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::UpdateRiskPool {
+            name: "crop".to_string(),
+            premium_rate: dec!("0.05"),
+            max_coverage: dec!("50"),
+            max_exposure_multiple: dec!("2"),
+        },
+    )
+    .expect_commit_success();
+
+    let receipt = test.purchase_policy(&dao, "crop", dec!("60"), SECONDS_IN_YEAR);
+    expect_failure_containing(&receipt, "this pool has no capacity left for the requested coverage");
+
+    test.purchase_policy(&dao, "crop", dec!("50"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::{ClaimDispute, ProposalAction};
+
+fn claim(test: &TestLedger, amount: Decimal) -> ProposalAction {
+    ProposalAction::PayClaim {
+        claimant: test.account(),
+        amount,
+    }
+}
+
+// posts a bond against the rejected claim, the appeal takes the next proposal id
+fn dispute(test: &mut TestLedger, dao: &mut Dao, proposal_id: usize, bond: Decimal) -> TransactionReceipt {
+    let account = test.account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, bond)
+        .take_all_from_worktop(XRD, "bond")
+        .call_method_with_name_lookup(dao.component, "dispute_claim", |lookup| {
+            (proposal_id, lookup.bucket("bond"), account, None::<ManifestProof>)
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    let receipt = test.execute(manifest);
+
+    if receipt.is_commit_success() {
+        dao.proposals += 1;
+    }
+
+    receipt
+}
+
+#[test]
+fn upheld_appeals_return_the_bond_and_pay_the_claim() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("appeals");
+    test.buy_tokens(&dao, dec!("400"));
+
+    let voter = test.new_actor();
+    test.buy_tokens_as(&voter, &dao, dec!("100"));
+
+    let rejected = test.reject_action(&mut dao, claim(&test, dec!("100")));
+
+    let account = test.account();
+    let account_before = test.xrd_balance(account);
+
+    // a tenth of the claim is bonded, the rest of the payment comes back
+    dispute(&mut test, &mut dao, rejected, dec!("25")).expect_commit_success();
+    let appeal = dao.proposals - 1;
+
+    assert_eq!(test.xrd_balance(account), account_before - dec!("10"));
+
+    let disputes: HashMap<usize, ClaimDispute> = test.get(dao.component, "get_claim_disputes", manifest_args!());
+    assert_eq!(disputes[&appeal].rejected_proposal_id, rejected);
+    assert_eq!(disputes[&appeal].bond_amount, dec!("10"));
+
+    // the appeal needs twice the quorum of the rejected claim
+    test.vote(&dao, appeal, false);
+    test.vote_as(&voter, &dao, appeal, false).expect_commit_success();
+
+    let receipt = test.call(dao.component, "resolve_appeal", manifest_args!(appeal));
+    expect_failure_containing(&receipt, "the appeal is still being voted on");
+
+    test.advance_time(14 * SECONDS_IN_DAY + 60);
+
+    test.call(dao.component, "resolve_appeal", manifest_args!(appeal))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(account), account_before);
+
+    test.execute_action(&dao, appeal).expect_commit_success();
+
+    assert_eq!(test.xrd_balance(account), account_before + dec!("100"));
+}
+
+#[test]
+fn failed_appeals_forfeit_the_bond_to_the_treasury() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("appeals");
+    test.buy_tokens(&dao, dec!("400"));
+
+    let rejected = test.reject_action(&mut dao, claim(&test, dec!("100")));
+
+    let receipt = dispute(&mut test, &mut dao, rejected, dec!("5"));
+    expect_failure_containing(&receipt, "insufficient dispute bond");
+
+    dispute(&mut test, &mut dao, rejected, dec!("10")).expect_commit_success();
+    let appeal = dao.proposals - 1;
+
+    // a single voter falls short of the doubled quorum
+    test.vote(&dao, appeal, false);
+
+    test.advance_time(14 * SECONDS_IN_DAY + 60);
+
+    let treasury_before: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+
+    test.call(dao.component, "resolve_appeal", manifest_args!(appeal))
+        .expect_commit_success();
+
+    let treasury: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+    assert_eq!(treasury, treasury_before + dec!("10"));
+
+    let receipt = test.execute_action(&dao, appeal);
+    expect_failure_containing(&receipt, "there is no current active proposal with the given ID");
+}
+
+#[test]
+fn only_rejected_claims_can_be_disputed() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("appeals");
+    test.buy_tokens(&dao, dec!("400"));
+
+    let approved = test.propose(&mut dao, claim(&test, dec!("100")));
+
+    let receipt = dispute(&mut test, &mut dao, approved, dec!("10"));
+    expect_failure_containing(&receipt, "the claim is still being voted on");
+
+    test.advance_time(60);
+
+    let receipt = dispute(&mut test, &mut dao, approved, dec!("10"));
+    expect_failure_containing(&receipt, "this claim was not rejected");
+}
@@ -0,0 +1,581 @@
+#![allow(dead_code)]
+
+use scrypto_test::prelude::*;
+
+use actus::bonding_curve::BondingCurve;
+use actus::events::{ProposalAction, ProposalCreationRight, VotingType};
+use actus::insurance::{CoverageTerms, PolicyTransferability, RiskPoolInfo, UnderwritingRules};
+use actus::oracle::ParametricTrigger;
+use actus::pricing::RiskClass;
+
+pub const TOKEN_SUPPLY: i32 = 1000;
+
+pub const SECONDS_IN_DAY: i64 = 24 * 60 * 60;
+
+pub const SECONDS_IN_YEAR: i64 = 365 * SECONDS_IN_DAY;
+
+// voting tokens put behind every proposal the helpers pass, they stay locked until voting ends
+pub const VOTE_AMOUNT: Decimal = dec!("10");
+
+pub struct Actor {
+    pub public_key: Secp256k1PublicKey,
+    pub account: ComponentAddress,
+}
+
+pub struct Dao {
+    pub component: ComponentAddress,
+    pub token: ResourceAddress,
+    pub owner_badge: ResourceAddress,
+    pub vesting_receipt: ResourceAddress,
+    pub member_badge: ResourceAddress,
+    // proposal ids are handed out in order, starting at zero
+    pub proposals: usize,
+}
+
+pub struct TestLedger {
+    pub ledger: DefaultLedgerSimulator,
+    pub package: PackageAddress,
+    pub user: Actor,
+    round: u64,
+}
+
+impl TestLedger {
+    pub fn new() -> Self {
+        let mut ledger = LedgerSimulatorBuilder::new().build();
+        let (public_key, _private_key, account) = ledger.new_allocated_account();
+        let package = ledger.compile_and_publish(this_package!());
+
+        Self {
+            ledger,
+            package,
+            user: Actor { public_key, account },
+            round: 1,
+        }
+    }
+
+    pub fn account(&self) -> ComponentAddress {
+        self.user.account
+    }
+
+    pub fn new_actor(&mut self) -> Actor {
+        let (public_key, _private_key, account) = self.ledger.new_allocated_account();
+
+        Actor { public_key, account }
+    }
+
+    pub fn execute(&mut self, manifest: TransactionManifestV1) -> TransactionReceipt {
+        let public_key = self.user.public_key;
+
+        self.ledger
+            .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&public_key)])
+    }
+
+    pub fn execute_as(&mut self, actor: &Actor, manifest: TransactionManifestV1) -> TransactionReceipt {
+        self.ledger
+            .execute_manifest(manifest, vec![NonFungibleGlobalId::from_public_key(&actor.public_key)])
+    }
+
+    // calls a method as the default user and deposits whatever it returns
+    pub fn call(&mut self, component: ComponentAddress, method: &str, args: impl ResolvableArguments) -> TransactionReceipt {
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component, method, args)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        self.execute(manifest)
+    }
+
+    // reads a value from a read-only method
+    pub fn get<T: ScryptoDecode>(&mut self, component: ComponentAddress, method: &str, args: impl ResolvableArguments) -> T {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component, method, args)
+            .build();
+
+        self.execute(manifest).expect_commit_success().output(1)
+    }
+
+    pub fn now(&mut self) -> UtcDateTime {
+        UtcDateTime::from_instant(&Instant::new(self.now_seconds())).unwrap()
+    }
+
+    pub fn now_seconds(&mut self) -> i64 {
+        self.ledger.get_current_proposer_timestamp_ms() / 1000
+    }
+
+    pub fn advance_time(&mut self, seconds: i64) {
+        self.round += 1;
+
+        let timestamp_ms = self.ledger.get_current_proposer_timestamp_ms() + seconds * 1000;
+
+        self.ledger
+            .advance_to_round_at_timestamp(Round::of(self.round), timestamp_ms);
+    }
+
+    pub fn balance(&mut self, component: ComponentAddress, resource: ResourceAddress) -> Decimal {
+        self.ledger.get_component_balance(component, resource)
+    }
+
+    pub fn xrd_balance(&mut self, component: ComponentAddress) -> Decimal {
+        self.balance(component, XRD)
+    }
+
+    pub fn instantiate_dao(&mut self, name: &str) -> Dao {
+        self.instantiate_dao_with_curve(name, None)
+    }
+
+    // a DAO selling its governance token at 1 XRD and buying it back at 1 XRD, or along `bonding_curve`
+    pub fn instantiate_dao_with_curve(&mut self, name: &str, bonding_curve: Option<BondingCurve>) -> Dao {
+        let receipt = self.try_instantiate_dao(name, bonding_curve);
+        let commit = receipt.expect_commit_success();
+
+        let component = commit.new_component_addresses()[0];
+        let resources = commit.new_resource_addresses().clone();
+
+        Dao {
+            component,
+            token: self.find_resource(&resources, "voting share"),
+            owner_badge: self.find_resource(&resources, "owner badge"),
+            vesting_receipt: self.find_resource(&resources, "vesting receipt"),
+            member_badge: self.find_resource(&resources, "membership badge"),
+            proposals: 0,
+        }
+    }
+
+    pub fn try_instantiate_dao(&mut self, name: &str, bonding_curve: Option<BondingCurve>) -> TransactionReceipt {
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                self.package,
+                "TokenWeigtedDao",
+                "initiate",
+                manifest_args!(
+                    name.to_string(),
+                    TOKEN_SUPPLY,
+                    0u8,
+                    dec!("1"),
+                    dec!("1"),
+                    "https://example.com/organization.png".to_string(),
+                    "https://example.com/token.png".to_string(),
+                    format!("{} mutual", name),
+                    Vec::<String>::new(),
+                    "insurance".to_string(),
+                    ProposalCreationRight::EVERYONE,
+                    format!("{} token", name),
+                    bonding_curve
+                ),
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        self.execute(manifest)
+    }
+
+    // picks a resource by the end of its name metadata
+    pub fn find_resource(&mut self, resources: &[ResourceAddress], name_suffix: &str) -> ResourceAddress {
+        resources
+            .iter()
+            .find(|resource| match self.ledger.get_metadata((**resource).into(), "name") {
+                Some(MetadataValue::String(name)) => name.ends_with(name_suffix),
+                _ => false,
+            })
+            .cloned()
+            .unwrap_or_else(|| panic!("no resource named '... {}'", name_suffix))
+    }
+
+    // buys governance tokens, which also funds the DAO's XRD treasury
+    pub fn buy_tokens(&mut self, dao: &Dao, amount: Decimal) {
+        let account = self.account();
+
+        let manifest = buy_tokens_manifest(dao, account, amount, amount * dec!("2"));
+
+        self.execute(manifest).expect_commit_success();
+    }
+
+    pub fn buy_tokens_as(&mut self, actor: &Actor, dao: &Dao, amount: Decimal) {
+        let manifest = buy_tokens_manifest(dao, actor.account, amount, amount * dec!("2"));
+
+        self.execute_as(actor, manifest).expect_commit_success();
+    }
+
+    // sells governance tokens back to the DAO
+    pub fn sell_tokens(&mut self, dao: &Dao, amount: Decimal) -> TransactionReceipt {
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, dao.token, amount)
+            .take_all_from_worktop(dao.token, "tokens")
+            .call_method_with_name_lookup(dao.component, "withdraw_power", |lookup| {
+                (lookup.bucket("tokens"), account)
+            })
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        self.execute(manifest)
+    }
+
+    pub fn stake_as(&mut self, actor: &Actor, dao: &Dao, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(actor.account, dao.token, amount)
+            .take_all_from_worktop(dao.token, "tokens")
+            .call_method_with_name_lookup(dao.component, "stake", |lookup| {
+                (lookup.bucket("tokens"), actor.account)
+            })
+            .build();
+
+        self.execute_as(actor, manifest)
+    }
+
+    // calls a method that only takes the caller's account, depositing what it returns
+    pub fn call_with_account_as(&mut self, actor: &Actor, dao: &Dao, method: &str) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(dao.component, method, manifest_args!(actor.account))
+            .try_deposit_entire_worktop_or_abort(actor.account, None)
+            .build();
+
+        self.execute_as(actor, manifest)
+    }
+
+    pub fn pay_premium(&mut self, dao: &Dao, amount: Decimal) -> TransactionReceipt {
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, amount)
+            .take_all_from_worktop(XRD, "premium")
+            .call_method_with_name_lookup(dao.component, "pay_premium", |lookup| {
+                (lookup.bucket("premium"), account)
+            })
+            .build();
+
+        self.execute(manifest)
+    }
+
+    // sends XRD straight into the treasury without buying tokens
+    pub fn contribute(&mut self, dao: &Dao, amount: Decimal) -> TransactionReceipt {
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, amount)
+            .take_all_from_worktop(XRD, "xrd")
+            .call_method_with_name_lookup(dao.component, "send_money_to_dao_treasury", |lookup| {
+                (lookup.bucket("xrd"), account)
+            })
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        self.execute(manifest)
+    }
+
+    // creates a proposal carrying `action` and votes for it; returns the proposal id
+    pub fn propose(&mut self, dao: &mut Dao, action: ProposalAction) -> usize {
+        let receipt = self.try_propose(dao, action);
+        receipt.expect_commit_success();
+
+        let proposal_id = dao.proposals;
+        dao.proposals += 1;
+
+        self.vote(dao, proposal_id, false);
+
+        proposal_id
+    }
+
+    // the proposal creation transaction alone, where validation failures surface
+    pub fn try_propose(&mut self, dao: &Dao, action: ProposalAction) -> TransactionReceipt {
+        let start = self.now();
+        let end = UtcDateTime::from_instant(&Instant::new(self.now_seconds() + 30)).unwrap();
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, dao.token, dec!("1"))
+            .take_all_from_worktop(dao.token, "tokens")
+            .call_method_with_name_lookup(dao.component, "create_action_praposal", |lookup| {
+                (
+                    "test proposal".to_string(),
+                    "proposal created by the ledger tests".to_string(),
+                    1u8,
+                    start,
+                    end,
+                    action,
+                    Some(account),
+                    lookup.bucket("tokens"),
+                    VotingType::ResourceHold,
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        self.execute(manifest)
+    }
+
+    pub fn vote(&mut self, dao: &Dao, proposal_id: usize, against: bool) -> TransactionReceipt {
+        let account = self.account();
+
+        let manifest = vote_manifest(dao, account, proposal_id, against);
+
+        let receipt = self.execute(manifest);
+        receipt.expect_commit_success();
+        receipt
+    }
+
+    pub fn vote_as(&mut self, actor: &Actor, dao: &Dao, proposal_id: usize, against: bool) -> TransactionReceipt {
+        let manifest = vote_manifest(dao, actor.account, proposal_id, against);
+
+        self.execute_as(actor, manifest)
+    }
+
+    pub fn execute_action(&mut self, dao: &Dao, proposal_id: usize) -> TransactionReceipt {
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(dao.component, "execute_action_proposal", manifest_args!(proposal_id))
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        self.execute(manifest)
+    }
+
+    // creates, approves and executes a proposal carrying `action`
+    pub fn pass_action(&mut self, dao: &mut Dao, action: ProposalAction) -> TransactionReceipt {
+        let proposal_id = self.propose(dao, action);
+
+        self.advance_time(60);
+
+        self.execute_action(dao, proposal_id)
+    }
+
+    // creates a proposal that is voted down and lets its voting period end
+    pub fn reject_action(&mut self, dao: &mut Dao, action: ProposalAction) -> usize {
+        self.try_propose(dao, action).expect_commit_success();
+
+        let proposal_id = dao.proposals;
+        dao.proposals += 1;
+
+        self.vote(dao, proposal_id, true);
+
+        self.advance_time(60);
+
+        proposal_id
+    }
+
+    // a risk pool funded from the treasury, selling plain policies with no waiting period
+    pub fn create_funded_pool(&mut self, dao: &mut Dao, name: &str, capital: Decimal) -> RiskPoolInfo {
+        self.pass_action(dao, create_pool_action(name, default_terms(), default_underwriting(), None, PolicyTransferability::Transferable))
+            .expect_commit_success();
+
+        self.fund_pool(dao, name, capital)
+    }
+
+    pub fn fund_pool(&mut self, dao: &mut Dao, name: &str, capital: Decimal) -> RiskPoolInfo {
+        self.pass_action(
+            dao,
+            ProposalAction::FundRiskPool {
+                name: name.to_string(),
+                amount: capital,
+            },
+        )
+        .expect_commit_success();
+
+        self.pool_info(dao, name)
+    }
+
+    pub fn pool_info(&mut self, dao: &Dao, name: &str) -> RiskPoolInfo {
+        self.get(dao.component, "get_risk_pool_info", manifest_args!(name.to_string()))
+    }
+
+    pub fn pool_address(&mut self, dao: &Dao, name: &str) -> ComponentAddress {
+        let pools: HashMap<String, ComponentAddress> = self.get(dao.component, "get_risk_pools", manifest_args!());
+
+        pools[name]
+    }
+
+    pub fn purchase_policy(&mut self, dao: &Dao, pool_name: &str, coverage: Decimal, duration_seconds: i64) -> TransactionReceipt {
+        let account = self.account();
+
+        self.purchase_policy_for(dao, pool_name, coverage, duration_seconds, account, None)
+    }
+
+    pub fn purchase_policy_for(
+        &mut self,
+        dao: &Dao,
+        pool_name: &str,
+        coverage: Decimal,
+        duration_seconds: i64,
+        buyer: ComponentAddress,
+        beneficiary: Option<ComponentAddress>,
+    ) -> TransactionReceipt {
+        let pool = self.pool_address(dao, pool_name);
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(buyer, XRD, dec!("100"))
+            .take_all_from_worktop(XRD, "premium")
+            .call_method_with_name_lookup(pool, "purchase_policy", |lookup| {
+                (
+                    lookup.bucket("premium"),
+                    coverage,
+                    duration_seconds,
+                    RiskClass::Low,
+                    beneficiary,
+                    buyer,
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(buyer, None)
+            .build();
+
+        self.execute(manifest)
+    }
+
+    // an index oracle reported by the default account, returns it with its reporter badge
+    pub fn instantiate_oracle(&mut self, description: &str) -> (ComponentAddress, ResourceAddress) {
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(self.package, "Oracle", "instantiate_oracle", manifest_args!(description.to_string()))
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        let receipt = self.execute(manifest);
+        let commit = receipt.expect_commit_success();
+
+        (commit.new_component_addresses()[0], commit.new_resource_addresses()[0])
+    }
+
+    pub fn report_value(&mut self, oracle: ComponentAddress, reporter_badge: ResourceAddress, value: Decimal) {
+        let account = self.account();
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account, reporter_badge, dec!("1"))
+            .call_method(oracle, "report_value", manifest_args!(value))
+            .build();
+
+        self.execute(manifest).expect_commit_success();
+    }
+
+    // pops a proof of the default account's policy as "policy" and lets `call` use it
+    pub fn call_with_policy(
+        &mut self,
+        dao: &Dao,
+        pool_name: &str,
+        policy_id: u64,
+        call: impl FnOnce(ManifestBuilder) -> ManifestBuilder,
+    ) -> TransactionReceipt {
+        let account = self.account();
+        let policy_resource = self.pool_info(dao, pool_name).policy_resource;
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(
+                account,
+                policy_resource,
+                [NonFungibleLocalId::integer(policy_id)],
+            )
+            .pop_from_auth_zone("policy");
+
+        let manifest = call(manifest)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        self.execute(manifest)
+    }
+
+    pub fn settle_parametric_claim(&mut self, dao: &Dao, pool_name: &str, policy_id: u64) -> TransactionReceipt {
+        let account = self.account();
+        let component = dao.component;
+
+        self.call_with_policy(dao, pool_name, policy_id, |builder| {
+            builder.call_method_with_name_lookup(component, "settle_parametric_claim", |lookup| {
+                (pool_name.to_string(), lookup.proof("policy"), account)
+            })
+        })
+    }
+}
+
+pub fn buy_tokens_manifest(dao: &Dao, account: ComponentAddress, amount: Decimal, max_payment: Decimal) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, max_payment)
+        .take_all_from_worktop(XRD, "xrd")
+        .call_method_with_name_lookup(dao.component, "obtain_community_token", |lookup| {
+            (lookup.bucket("xrd"), amount)
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build()
+}
+
+pub fn vote_manifest(dao: &Dao, account: ComponentAddress, proposal_id: usize, against: bool) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, dao.token, VOTE_AMOUNT)
+        .take_all_from_worktop(dao.token, "tokens")
+        .call_method_with_name_lookup(dao.component, "vote", |lookup| {
+            (lookup.bucket("tokens"), against, account, proposal_id)
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build()
+}
+
+pub fn default_terms() -> CoverageTerms {
+    CoverageTerms {
+        deductible: Decimal::zero(),
+        coinsurance: Decimal::zero(),
+        per_incident_limit: dec!("1000"),
+        annual_aggregate_limit: dec!("1000"),
+        exclusions: Vec::new(),
+    }
+}
+
+pub fn default_underwriting() -> UnderwritingRules {
+    UnderwritingRules {
+        waiting_period_seconds: 0,
+        max_cover_per_account: dec!("1000"),
+        cover_window_epochs: 10,
+    }
+}
+
+pub fn create_pool_action(
+    name: &str,
+    terms: CoverageTerms,
+    underwriting: UnderwritingRules,
+    parametric_trigger: Option<ParametricTrigger>,
+    transferability: PolicyTransferability,
+) -> ProposalAction {
+    ProposalAction::CreateRiskPool {
+        name: name.to_string(),
+        premium_rate: dec!("0.05"),
+        max_coverage: dec!("1000"),
+        max_exposure_multiple: dec!("2"),
+        parametric_trigger,
+        transferability,
+        terms,
+        underwriting,
+    }
+}
+
+pub fn pay_policy_claim_action(pool_name: &str, policy_id: u64, amount: Decimal, incident_time: i64) -> ProposalAction {
+    ProposalAction::PayPolicyClaim {
+        pool_name: pool_name.to_string(),
+        policy_id: NonFungibleLocalId::integer(policy_id),
+        amount,
+        incident_category: "hack".to_string(),
+        incident_time,
+    }
+}
+
+// asserts the transaction failed with `message` somewhere in its error
+pub fn expect_failure_containing(receipt: &TransactionReceipt, message: &str) {
+    receipt.expect_specific_failure(|error| format!("{:?}", error).contains(message));
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+use actus::insurance::{CoveragePolicy, CoverageTerms, PolicyTransferability};
+
+fn pool_with_terms(test: &mut TestLedger) -> Dao {
+    let mut dao = test.instantiate_dao("terms");
+    test.buy_tokens(&dao, dec!("400"));
+
+    let terms = CoverageTerms {
+        deductible: dec!("10"),
+        coinsurance: dec!("0.2"),
+        per_incident_limit: dec!("50"),
+        annual_aggregate_limit: dec!("80"),
+        exclusions: vec!["flood".to_string()],
+    };
+
+    test.pass_action(
+        &mut dao,
+        create_pool_action("crop", terms, default_underwriting(), None, PolicyTransferability::Transferable),
+    )
+    .expect_commit_success();
+
+    test.fund_pool(&mut dao, "crop", dec!("200"));
+
+    test.purchase_policy(&dao, "crop", dec!("200"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    dao
+}
+
+#[test]
+fn payouts_apply_the_deductible_coinsurance_and_limits_in_order() {
+    let mut test = TestLedger::new();
+
+    let mut dao = pool_with_terms(&mut test);
+
+    let account = test.account();
+    let account_before = test.xrd_balance(account);
+
+    // 100 less 10 deductible is 90, the holder keeps 20% leaving 72, capped at 50 per incident
+    let incident_time = test.now_seconds();
+    test.pass_action(&mut dao, pay_policy_claim_action("crop", 0, dec!("100"), incident_time))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(account), account_before + dec!("50"));
+
+    // 60 becomes 40, only 30 of the 80 annual aggregate is left
+    let incident_time = test.now_seconds();
+    test.pass_action(&mut dao, pay_policy_claim_action("crop", 0, dec!("60"), incident_time))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(account), account_before + dec!("80"));
+
+    let pool = test.pool_address(&dao, "crop");
+    let policy: CoveragePolicy = test.get(pool, "get_policy", manifest_args!(NonFungibleLocalId::integer(0)));
+
+    assert_eq!(policy.claimed_amount, dec!("80"));
+    assert_eq!(policy.claimed_in_year, dec!("80"));
+    assert_eq!(test.pool_info(&dao, "crop").total_claims_paid, dec!("80"));
+}
+
+#[test]
+fn excluded_incident_categories_are_rejected() {
+    let mut test = TestLedger::new();
+
+    let dao = pool_with_terms(&mut test);
+
+    let incident_time = test.now_seconds();
+
+    let receipt = test.try_propose(
+        &dao,
+        ProposalAction::PayPolicyClaim {
+            pool_name: "crop".to_string(),
+            policy_id: NonFungibleLocalId::integer(0),
+            amount: dec!("100"),
+            incident_category: "flood".to_string(),
+            incident_time,
+        },
+    );
+    expect_failure_containing(&receipt, "this incident category is excluded from the policy");
+}
+
+#[test]
+fn pools_reject_invalid_terms() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("terms");
+    test.buy_tokens(&dao, dec!("400"));
+
+    let terms = CoverageTerms {
+        coinsurance: dec!("1"),
+        ..default_terms()
+    };
+
+    let receipt = test.try_propose(
+        &dao,
+        create_pool_action("crop", terms, default_underwriting(), None, PolicyTransferability::Transferable),
+    );
+    expect_failure_containing(&receipt, "co-insurance must be at least 0 and below 1");
+}
@@ -0,0 +1,141 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::ann::RateResetTerms;
+
+struct Oracle {
+    component: ComponentAddress,
+    admin_badge: ResourceAddress,
+}
+
+fn instantiate_oracle(test: &mut TestLedger, initial_rate: Decimal) -> Oracle {
+    let account = test.account();
+    let package = test.package;
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package,
+            "RateOracle",
+            "instantiate_rate_oracle",
+            manifest_args!("reference rate".to_string(), initial_rate),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    let receipt = test.execute(manifest);
+    let commit = receipt.expect_commit_success();
+
+    Oracle {
+        component: commit.new_component_addresses()[0],
+        admin_badge: commit.new_resource_addresses()[0],
+    }
+}
+
+fn update_rate(test: &mut TestLedger, oracle: &Oracle, new_rate: Decimal) -> TransactionReceipt {
+    let account = test.account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(account, oracle.admin_badge, dec!("1"))
+        .call_method(oracle.component, "update_rate", manifest_args!(new_rate))
+        .build();
+
+    test.execute(manifest)
+}
+
+// a five year annuity at 4% re-fixed daily to the reference rate plus 1%, between 2% and 6%
+fn issue_floating_annuity(test: &mut TestLedger, dao: &Dao, oracle: &Oracle) -> ComponentAddress {
+    let now = test.now_seconds() as u64;
+    let issuer = test.account();
+
+    let rate_reset = RateResetTerms {
+        rate_oracle: oracle.component,
+        cycle_of_rate_reset: SECONDS_IN_DAY as u64,
+        rate_spread: dec!("1"),
+        rate_floor: dec!("2"),
+        rate_cap: dec!("6"),
+    };
+
+    test.get(
+        dao.component,
+        "issue_ann_token",
+        manifest_args!(
+            "ANN".to_string(),
+            "RPA".to_string(),
+            "floating-1".to_string(),
+            dec!("4"),
+            "XRD".to_string(),
+            now,
+            now + 5 * SECONDS_IN_YEAR as u64,
+            dec!("100"),
+            "long".to_string(),
+            dec!("100"),
+            dec!("10"),
+            issuer,
+            Decimal::zero(),
+            Some(rate_reset)
+        ),
+    )
+}
+
+#[test]
+fn rate_reset_follows_the_oracle_within_floor_and_cap() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("floating");
+    let oracle = instantiate_oracle(&mut test, dec!("3"));
+    let annuity = issue_floating_annuity(&mut test, &dao, &oracle);
+
+    test.advance_time(SECONDS_IN_DAY);
+
+    let rate: Decimal = test.get(annuity, "reset_rate", manifest_args!());
+    assert_eq!(rate, dec!("4"));
+
+    update_rate(&mut test, &oracle, dec!("10")).expect_commit_success();
+    test.advance_time(SECONDS_IN_DAY);
+
+    let rate: Decimal = test.get(annuity, "reset_rate", manifest_args!());
+    assert_eq!(rate, dec!("6"));
+
+    update_rate(&mut test, &oracle, Decimal::zero()).expect_commit_success();
+    test.advance_time(SECONDS_IN_DAY);
+
+    let rate: Decimal = test.get(annuity, "reset_rate", manifest_args!());
+    assert_eq!(rate, dec!("2"));
+}
+
+#[test]
+fn rate_cannot_be_reset_before_the_next_reset_date() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("floating");
+    let oracle = instantiate_oracle(&mut test, dec!("3"));
+    let annuity = issue_floating_annuity(&mut test, &dao, &oracle);
+
+    let receipt = test.call(annuity, "reset_rate", manifest_args!());
+
+    expect_failure_containing(&receipt, "The next rate reset is not due yet.");
+
+    let rate: Decimal = test.get(annuity, "get_nominal_interest_rate", manifest_args!());
+    assert_eq!(rate, dec!("4"));
+}
+
+#[test]
+fn only_the_oracle_admin_can_update_the_reference_rate() {
+    let mut test = TestLedger::new();
+
+    let oracle = instantiate_oracle(&mut test, dec!("3"));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(oracle.component, "update_rate", manifest_args!(dec!("10")))
+        .build();
+
+    test.execute(manifest).expect_commit_failure();
+
+    let rate: Decimal = test.get(oracle.component, "get_rate", manifest_args!());
+    assert_eq!(rate, dec!("3"));
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+use actus::membership::{MemberBadge, MembershipTier};
+
+fn contribute_as(test: &mut TestLedger, member: &Actor, dao: &Dao, amount: Decimal) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(member.account, XRD, amount)
+        .take_all_from_worktop(XRD, "xrd")
+        .call_method_with_name_lookup(dao.component, "send_money_to_dao_treasury", |lookup| {
+            (lookup.bucket("xrd"), member.account)
+        })
+        .build();
+
+    test.execute_as(member, manifest).expect_commit_success();
+}
+
+fn claim_rewards(test: &mut TestLedger, member: &Actor, dao: &Dao, badge_id: u64) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            member.account,
+            dao.member_badge,
+            [NonFungibleLocalId::integer(badge_id)],
+        )
+        .take_all_from_worktop(dao.member_badge, "badge")
+        .call_method_with_name_lookup(dao.component, "claim_member_rewards", |lookup| {
+            (lookup.bucket("badge"),)
+        })
+        .try_deposit_entire_worktop_or_abort(member.account, None)
+        .build();
+
+    test.execute_as(member, manifest)
+}
+
+#[test]
+fn first_contribution_mints_a_badge_that_tracks_the_members_tier() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("members");
+    let member = test.new_actor();
+
+    contribute_as(&mut test, &member, &dao, dec!("400"));
+
+    assert_eq!(test.balance(member.account, dao.member_badge), dec!("1"));
+
+    let badge: MemberBadge = test
+        .ledger
+        .get_non_fungible_data(dao.member_badge, NonFungibleLocalId::integer(0));
+    assert_eq!(badge.member, member.account);
+    assert_eq!(badge.cumulative_contribution, dec!("400"));
+    assert_eq!(badge.tier, MembershipTier::Bronze);
+
+    contribute_as(&mut test, &member, &dao, dec!("600"));
+
+    // the same badge is updated rather than a second one minted
+    assert_eq!(test.balance(member.account, dao.member_badge), dec!("1"));
+
+    let badge: MemberBadge = test
+        .ledger
+        .get_non_fungible_data(dao.member_badge, NonFungibleLocalId::integer(0));
+    assert_eq!(badge.cumulative_contribution, dec!("1000"));
+    assert_eq!(badge.tier, MembershipTier::Silver);
+}
+
+#[test]
+fn rewards_are_shared_in_proportion_to_contributions() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("members");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let first = test.new_actor();
+    let second = test.new_actor();
+
+    contribute_as(&mut test, &first, &dao, dec!("30"));
+    contribute_as(&mut test, &second, &dao, dec!("10"));
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::DistributeMemberRewards {
+            resource_address: XRD,
+            amount: dec!("40"),
+        },
+    )
+    .expect_commit_success();
+
+    let first_before = test.xrd_balance(first.account);
+    let second_before = test.xrd_balance(second.account);
+
+    claim_rewards(&mut test, &first, &dao, 0).expect_commit_success();
+    claim_rewards(&mut test, &second, &dao, 1).expect_commit_success();
+
+    assert_eq!(test.xrd_balance(first.account), first_before + dec!("30"));
+    assert_eq!(test.xrd_balance(second.account), second_before + dec!("10"));
+
+    // badges come back with the rewards
+    assert_eq!(test.balance(first.account, dao.member_badge), dec!("1"));
+
+    let receipt = claim_rewards(&mut test, &first, &dao, 0);
+    expect_failure_containing(&receipt, "there are no rewards to claim for this badge");
+}
+
+#[test]
+fn rewards_need_at_least_one_member() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("members");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let receipt = test.try_propose(
+        &dao,
+        ProposalAction::DistributeMemberRewards {
+            resource_address: XRD,
+            amount: dec!("40"),
+        },
+    );
+
+    expect_failure_containing(&receipt, "there are no members to reward");
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+
+fn fund_treasury(test: &mut TestLedger, dao: &Dao, resource: ResourceAddress, amount: Decimal) -> TransactionReceipt {
+    let account = test.account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource, amount)
+        .take_all_from_worktop(resource, "payment")
+        .call_method_with_name_lookup(dao.component, "fund_treasury", |lookup| (lookup.bucket("payment"),))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    test.execute(manifest)
+}
+
+#[test]
+fn whitelisted_assets_are_held_and_transferred_from_their_own_vault() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("multi");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let account = test.account();
+    let usd = test.ledger.create_fungible_resource(dec!("1000"), 18, account);
+
+    test.pass_action(&mut dao, ProposalAction::WhitelistAsset(usd))
+        .expect_commit_success();
+
+    fund_treasury(&mut test, &dao, usd, dec!("250")).expect_commit_success();
+
+    let treasury: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(usd));
+    assert_eq!(treasury, dec!("250"));
+
+    let recipient = test.new_actor();
+    let xrd_before = test.xrd_balance(dao.component);
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::TransferAsset {
+            resource_address: usd,
+            amount: dec!("100"),
+            recipient: recipient.account,
+        },
+    )
+    .expect_commit_success();
+
+    assert_eq!(test.balance(recipient.account, usd), dec!("100"));
+    assert_eq!(test.balance(dao.component, usd), dec!("150"));
+    assert_eq!(test.xrd_balance(dao.component), xrd_before);
+}
+
+#[test]
+fn assets_outside_the_whitelist_are_refused() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("multi");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let account = test.account();
+    let usd = test.ledger.create_fungible_resource(dec!("1000"), 18, account);
+
+    let receipt = fund_treasury(&mut test, &dao, usd, dec!("250"));
+    expect_failure_containing(&receipt, "this resource is not whitelisted for the treasury");
+
+    let receipt = test.try_propose(
+        &dao,
+        ProposalAction::TransferAsset {
+            resource_address: usd,
+            amount: dec!("100"),
+            recipient: account,
+        },
+    );
+    expect_failure_containing(&receipt, "only whitelisted assets can be transferred from the treasury");
+}
+
+#[test]
+fn votes_stay_locked_until_voting_ends() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("multi");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let account = test.account();
+
+    let receipt = test.try_propose(&dao, ProposalAction::SetClaimReserveRatio(dec!("0.5")));
+    let proposal = receipt.expect_commit_success().new_component_addresses()[0];
+
+    test.vote(&dao, 0, false);
+
+    let receipt = test.execute(vote_manifest(&dao, account, 0, false));
+    expect_failure_containing(&receipt, "You have already voted on this proposal.");
+
+    let release = |test: &mut TestLedger| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(proposal, "release_votes", manifest_args!(account))
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        test.execute(manifest)
+    };
+
+    expect_failure_containing(&release(&mut test), "votes are locked until voting ends");
+
+    test.advance_time(60);
+
+    let voter = test.new_actor();
+    test.buy_tokens_as(&voter, &dao, dec!("20"));
+
+    let receipt = test.vote_as(&voter, &dao, 0, false);
+    expect_failure_containing(&receipt, "voting is not open for this proposal");
+
+    let tokens_before = test.balance(account, dao.token);
+
+    release(&mut test).expect_commit_success();
+
+    assert_eq!(test.balance(account, dao.token), tokens_before + VOTE_AMOUNT);
+}
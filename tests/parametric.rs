@@ -0,0 +1,120 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::insurance::PolicyTransferability;
+use actus::oracle::{ParametricTrigger, TriggerDirection};
+
+struct Setup {
+    dao: Dao,
+    oracle: ComponentAddress,
+    reporter_badge: ResourceAddress,
+}
+
+// a pool paying out in full once the oracle reports 100 or more, and a 100 XRD policy from it
+fn setup(test: &mut TestLedger) -> Setup {
+    let mut dao = test.instantiate_dao("parametric");
+    test.buy_tokens(&dao, dec!("400"));
+
+    let (oracle, reporter_badge) = test.instantiate_oracle("rainfall");
+
+    let trigger = ParametricTrigger {
+        oracle,
+        direction: TriggerDirection::Above,
+        threshold: dec!("100"),
+    };
+
+    test.pass_action(
+        &mut dao,
+        create_pool_action(
+            "rain",
+            default_terms(),
+            default_underwriting(),
+            Some(trigger),
+            PolicyTransferability::Transferable,
+        ),
+    )
+    .expect_commit_success();
+
+    test.fund_pool(&mut dao, "rain", dec!("200"));
+
+    test.purchase_policy(&dao, "rain", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    Setup {
+        dao,
+        oracle,
+        reporter_badge,
+    }
+}
+
+#[test]
+fn triggered_policies_settle_in_full_without_a_vote() {
+    let mut test = TestLedger::new();
+
+    let setup = setup(&mut test);
+
+    test.advance_time(60);
+    test.report_value(setup.oracle, setup.reporter_badge, dec!("120"));
+
+    let account = test.account();
+    let account_before = test.xrd_balance(account);
+
+    test.settle_parametric_claim(&setup.dao, "rain", 0)
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(account), account_before + dec!("100"));
+    assert_eq!(test.pool_info(&setup.dao, "rain").total_claims_paid, dec!("100"));
+
+    let receipt = test.settle_parametric_claim(&setup.dao, "rain", 0);
+    expect_failure_containing(&receipt, "this policy has already been paid out");
+}
+
+#[test]
+fn policies_do_not_settle_until_the_trigger_is_reached() {
+    let mut test = TestLedger::new();
+
+    let setup = setup(&mut test);
+
+    test.advance_time(60);
+    test.report_value(setup.oracle, setup.reporter_badge, dec!("80"));
+
+    let receipt = test.settle_parametric_claim(&setup.dao, "rain", 0);
+    expect_failure_containing(&receipt, "the parametric condition did not hold during the coverage period");
+
+    assert_eq!(test.pool_info(&setup.dao, "rain").total_claims_paid, Decimal::zero());
+}
+
+#[test]
+fn readings_before_the_policy_started_do_not_count() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("parametric");
+    test.buy_tokens(&dao, dec!("400"));
+
+    let (oracle, reporter_badge) = test.instantiate_oracle("rainfall");
+
+    test.report_value(oracle, reporter_badge, dec!("150"));
+    test.advance_time(60);
+
+    let trigger = ParametricTrigger {
+        oracle,
+        direction: TriggerDirection::Above,
+        threshold: dec!("100"),
+    };
+
+    test.pass_action(
+        &mut dao,
+        create_pool_action("rain", default_terms(), default_underwriting(), Some(trigger), PolicyTransferability::Transferable),
+    )
+    .expect_commit_success();
+
+    test.fund_pool(&mut dao, "rain", dec!("200"));
+
+    test.purchase_policy(&dao, "rain", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let receipt = test.settle_parametric_claim(&dao, "rain", 0);
+    expect_failure_containing(&receipt, "the parametric condition did not hold during the coverage period");
+}
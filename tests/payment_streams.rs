@@ -0,0 +1,121 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+
+// streams 100 XRD to `recipient` at 0.1 XRD a second, starting when the proposal executes
+fn create_stream(test: &mut TestLedger, dao: &mut Dao, recipient: ComponentAddress) -> i64 {
+    let start_time = test.now_seconds() + 60;
+
+    test.pass_action(
+        dao,
+        ProposalAction::CreateStream {
+            recipient,
+            resource_address: XRD,
+            rate_per_second: dec!("0.1"),
+            start_time,
+            end_time: start_time + 1000,
+        },
+    )
+    .expect_commit_success();
+
+    start_time
+}
+
+fn withdraw(test: &mut TestLedger, dao: &Dao, recipient: &Actor) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(dao.component, "withdraw_from_stream", manifest_args!(0u64, recipient.account))
+        .try_deposit_entire_worktop_or_abort(recipient.account, None)
+        .build();
+
+    test.execute_as(recipient, manifest)
+}
+
+#[test]
+fn recipient_withdraws_what_has_streamed_and_cancelling_refunds_the_rest() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("streams");
+    test.buy_tokens(&dao, dec!("200"));
+
+    let recipient = test.new_actor();
+    let treasury_before = test.xrd_balance(dao.component);
+
+    create_stream(&mut test, &mut dao, recipient.account);
+
+    // the stream vault stays with the DAO component
+    assert_eq!(test.xrd_balance(dao.component), treasury_before);
+
+    let treasury: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+    assert_eq!(treasury, treasury_before - dec!("100"));
+
+    test.advance_time(400);
+
+    let withdrawable: Decimal = test.get(dao.component, "get_stream_withdrawable_amount", manifest_args!(0u64));
+    assert_eq!(withdrawable, dec!("40"));
+
+    let recipient_before = test.xrd_balance(recipient.account);
+
+    withdraw(&mut test, &dao, &recipient).expect_commit_success();
+
+    assert_eq!(test.xrd_balance(recipient.account), recipient_before + dec!("40"));
+
+    // executes 60 seconds later, after 46 XRD has streamed
+    test.pass_action(&mut dao, ProposalAction::CancelStream { stream_id: 0 })
+        .expect_commit_success();
+
+    let withdrawable: Decimal = test.get(dao.component, "get_stream_withdrawable_amount", manifest_args!(0u64));
+    assert_eq!(withdrawable, dec!("6"));
+
+    let treasury: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+    assert_eq!(treasury, treasury_before - dec!("46"));
+
+    test.advance_time(1000);
+
+    let withdrawable: Decimal = test.get(dao.component, "get_stream_withdrawable_amount", manifest_args!(0u64));
+    assert_eq!(withdrawable, dec!("6"));
+
+    withdraw(&mut test, &dao, &recipient).expect_commit_success();
+
+    let receipt = withdraw(&mut test, &dao, &recipient);
+    expect_failure_containing(&receipt, "nothing has streamed since the last withdrawal");
+}
+
+#[test]
+fn streams_cannot_be_cancelled_twice() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("streams");
+    test.buy_tokens(&dao, dec!("200"));
+
+    let recipient = test.new_actor();
+
+    create_stream(&mut test, &mut dao, recipient.account);
+
+    test.pass_action(&mut dao, ProposalAction::CancelStream { stream_id: 0 })
+        .expect_commit_success();
+
+    let receipt = test.try_propose(&dao, ProposalAction::CancelStream { stream_id: 0 });
+    expect_failure_containing(&receipt, "this payment stream is already cancelled");
+}
+
+#[test]
+fn only_the_recipient_can_withdraw_from_a_stream() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("streams");
+    test.buy_tokens(&dao, dec!("200"));
+
+    let recipient = test.new_actor();
+    let stranger = test.new_actor();
+
+    create_stream(&mut test, &mut dao, recipient.account);
+
+    test.advance_time(400);
+
+    let receipt = withdraw(&mut test, &dao, &stranger);
+    expect_failure_containing(&receipt, "only the stream recipient can withdraw from it");
+}
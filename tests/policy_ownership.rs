@@ -0,0 +1,163 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+use actus::insurance::PolicyTransferability;
+
+// creates and funds a pool of the given kind, returning the resource of its transfer permits
+fn pool(test: &mut TestLedger, dao: &mut Dao, transferability: PolicyTransferability) -> ResourceAddress {
+    let receipt = test.pass_action(
+        dao,
+        create_pool_action("crop", default_terms(), default_underwriting(), None, transferability),
+    );
+    let resources = receipt.expect_commit_success().new_resource_addresses().clone();
+
+    let policy_resource = test.fund_pool(dao, "crop", dec!("200")).policy_resource;
+
+    resources
+        .into_iter()
+        .find(|resource| *resource != policy_resource)
+        .unwrap()
+}
+
+#[test]
+fn soulbound_policies_can_not_leave_the_holder() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("ownership");
+    test.buy_tokens(&dao, dec!("400"));
+
+    pool(&mut test, &mut dao, PolicyTransferability::Soulbound);
+
+    test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let account = test.account();
+    let other = test.new_actor();
+    let policy_resource = test.pool_info(&dao, "crop").policy_resource;
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(account, policy_resource, [NonFungibleLocalId::integer(0)])
+        .try_deposit_entire_worktop_or_abort(other.account, None)
+        .build();
+    test.execute(manifest).expect_commit_failure();
+
+    assert_eq!(test.balance(account, policy_resource), dec!("1"));
+
+    let receipt = test.try_propose(
+        &dao,
+        ProposalAction::ApprovePolicyTransfer {
+            pool_name: "crop".to_string(),
+            policy_id: NonFungibleLocalId::integer(0),
+            new_holder: other.account,
+        },
+    );
+    expect_failure_containing(&receipt, "policies of this pool are not transferred through DAO approval");
+}
+
+#[test]
+fn payouts_go_to_the_designated_beneficiary() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("ownership");
+    test.buy_tokens(&dao, dec!("400"));
+
+    pool(&mut test, &mut dao, PolicyTransferability::Soulbound);
+
+    let account = test.account();
+    let family = test.new_actor();
+    let charity = test.new_actor();
+
+    test.purchase_policy_for(&dao, "crop", dec!("100"), SECONDS_IN_YEAR, account, Some(family.account))
+        .expect_commit_success();
+
+    let account_before = test.xrd_balance(account);
+    let family_before = test.xrd_balance(family.account);
+
+    let incident_time = test.now_seconds();
+    test.pass_action(&mut dao, pay_policy_claim_action("crop", 0, dec!("30"), incident_time))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(family.account), family_before + dec!("30"));
+    assert_eq!(test.xrd_balance(account), account_before);
+
+    // the holder can name someone else for later payouts
+    let pool = test.pool_address(&dao, "crop");
+    let charity_account = charity.account;
+
+    test.call_with_policy(&dao, "crop", 0, |builder| {
+        builder.call_method_with_name_lookup(pool, "designate_beneficiary", |lookup| {
+            (lookup.proof("policy"), charity_account)
+        })
+    })
+    .expect_commit_success();
+
+    let charity_before = test.xrd_balance(charity.account);
+
+    let incident_time = test.now_seconds();
+    test.pass_action(&mut dao, pay_policy_claim_action("crop", 0, dec!("20"), incident_time))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(charity.account), charity_before + dec!("20"));
+    assert_eq!(test.xrd_balance(family.account), family_before + dec!("30"));
+}
+
+#[test]
+fn dao_approved_transfers_reissue_the_policy_to_the_new_holder() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("ownership");
+    test.buy_tokens(&dao, dec!("400"));
+
+    let permit_resource = pool(&mut test, &mut dao, PolicyTransferability::DaoApproved);
+
+    test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let account = test.account();
+    let buyer = test.new_actor();
+    let policy_resource = test.pool_info(&dao, "crop").policy_resource;
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::ApprovePolicyTransfer {
+            pool_name: "crop".to_string(),
+            policy_id: NonFungibleLocalId::integer(0),
+            new_holder: buyer.account,
+        },
+    )
+    .expect_commit_success();
+
+    assert_eq!(test.balance(account, permit_resource), dec!("1"));
+
+    let pool = test.pool_address(&dao, "crop");
+
+    test.call_with_policy(&dao, "crop", 0, |builder| {
+        builder
+            .withdraw_from_account(account, permit_resource, dec!("1"))
+            .take_all_from_worktop(permit_resource, "permit")
+            .call_method_with_name_lookup(pool, "transfer_policy", |lookup| {
+                (lookup.proof("policy"), lookup.bucket("permit"))
+            })
+    })
+    .expect_commit_success();
+
+    assert_eq!(test.balance(buyer.account, policy_resource), dec!("1"));
+    assert_eq!(test.balance(account, permit_resource), Decimal::zero());
+
+    // the voided policy pays nothing, the reissued one pays its new holder
+    let incident_time = test.now_seconds();
+
+    let receipt = test.try_propose(&dao, pay_policy_claim_action("crop", 0, dec!("50"), incident_time));
+    expect_failure_containing(&receipt, "this policy was reissued to a new holder");
+
+    let buyer_before = test.xrd_balance(buyer.account);
+
+    test.pass_action(&mut dao, pay_policy_claim_action("crop", 1, dec!("50"), incident_time))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(buyer.account), buyer_before + dec!("50"));
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+
+fn offer_treaty(test: &mut TestLedger, reinsurer: &mut Dao, ceding: &Dao) {
+    test.pass_action(
+        reinsurer,
+        ProposalAction::OfferReinsurance {
+            ceding_dao: ceding.component,
+            premium: dec!("100"),
+            attachment_point: dec!("50"),
+            limit: dec!("500"),
+            duration_seconds: SECONDS_IN_YEAR,
+        },
+    )
+    .expect_commit_success();
+}
+
+fn buy_treaty(test: &mut TestLedger, reinsurer: &Dao, ceding: &mut Dao) {
+    test.pass_action(
+        ceding,
+        ProposalAction::BuyReinsurance {
+            reinsurer: reinsurer.component,
+            treaty_id: 0,
+            premium: dec!("100"),
+        },
+    )
+    .expect_commit_success();
+}
+
+// the reinsurer holds enough free XRD to back the 500 XRD limit once the premium lands
+fn instantiate_funded_daos(test: &mut TestLedger) -> (Dao, Dao) {
+    let reinsurer = test.instantiate_dao("reinsurer");
+    let ceding = test.instantiate_dao("ceding");
+
+    test.buy_tokens(&reinsurer, dec!("500"));
+    test.buy_tokens(&ceding, dec!("300"));
+
+    (reinsurer, ceding)
+}
+
+// reinsurer writes a treaty with a 50 XRD attachment point and a 500 XRD limit,
+// the ceding DAO buys it for 100 XRD
+fn setup_treaty(test: &mut TestLedger) -> (Dao, Dao) {
+    let (mut reinsurer, mut ceding) = instantiate_funded_daos(test);
+
+    offer_treaty(test, &mut reinsurer, &ceding);
+    buy_treaty(test, &reinsurer, &mut ceding);
+
+    (reinsurer, ceding)
+}
+
+fn recover_from_reinsurance(test: &mut TestLedger, dao: &Dao) {
+    test.call(dao.component, "recover_from_reinsurance", manifest_args!())
+        .expect_commit_success();
+}
+
+#[test]
+fn premium_moves_from_ceding_dao_to_reinsurer_treasury() {
+    let mut test = TestLedger::new();
+
+    let (mut reinsurer, mut ceding) = instantiate_funded_daos(&mut test);
+
+    offer_treaty(&mut test, &mut reinsurer, &ceding);
+
+    let reinsurer_before = test.xrd_balance(reinsurer.component);
+    let ceding_before = test.xrd_balance(ceding.component);
+
+    buy_treaty(&mut test, &reinsurer, &mut ceding);
+
+    assert_eq!(test.xrd_balance(reinsurer.component), reinsurer_before + dec!("100"));
+    assert_eq!(test.xrd_balance(ceding.component), ceding_before - dec!("100"));
+
+    // the written limit is reserved out of the reinsurer's free balance
+    let reserve: Decimal = test.get(reinsurer.component, "get_reinsurance_reserve", manifest_args!());
+    assert_eq!(reserve, dec!("500"));
+}
+
+#[test]
+fn ceding_dao_draws_claims_above_the_attachment_point() {
+    let mut test = TestLedger::new();
+
+    let (reinsurer, mut ceding) = setup_treaty(&mut test);
+
+    let account = test.account();
+
+    test.pass_action(
+        &mut ceding,
+        ProposalAction::PayClaim {
+            claimant: account,
+            amount: dec!("200"),
+        },
+    )
+    .expect_commit_success();
+
+    let reinsurer_before = test.xrd_balance(reinsurer.component);
+
+    recover_from_reinsurance(&mut test, &ceding);
+
+    // 200 of claims less the 50 attachment point
+    assert_eq!(test.xrd_balance(reinsurer.component), reinsurer_before - dec!("150"));
+
+    // nothing more is owed until further claims are paid
+    recover_from_reinsurance(&mut test, &ceding);
+
+    assert_eq!(test.xrd_balance(reinsurer.component), reinsurer_before - dec!("150"));
+}
+
+#[test]
+fn claims_below_the_attachment_point_are_not_recovered() {
+    let mut test = TestLedger::new();
+
+    let (reinsurer, mut ceding) = setup_treaty(&mut test);
+
+    let account = test.account();
+
+    test.pass_action(
+        &mut ceding,
+        ProposalAction::PayClaim {
+            claimant: account,
+            amount: dec!("40"),
+        },
+    )
+    .expect_commit_success();
+
+    let reinsurer_before = test.xrd_balance(reinsurer.component);
+
+    recover_from_reinsurance(&mut test, &ceding);
+
+    assert_eq!(test.xrd_balance(reinsurer.component), reinsurer_before);
+}
+
+#[test]
+fn only_the_ceding_dao_can_draw_on_a_treaty() {
+    let mut test = TestLedger::new();
+
+    let (reinsurer, _ceding) = setup_treaty(&mut test);
+
+    test.call(reinsurer.component, "draw_reinsurance", manifest_args!(0u64, dec!("1000")))
+        .expect_commit_failure();
+}
+
+#[test]
+fn treaty_cannot_be_written_beyond_the_free_balance() {
+    let mut test = TestLedger::new();
+
+    let mut reinsurer = test.instantiate_dao("reinsurer");
+    let mut ceding = test.instantiate_dao("ceding");
+
+    // 100 of treasury plus the 100 premium cannot back a 500 limit
+    test.buy_tokens(&reinsurer, dec!("100"));
+    test.buy_tokens(&ceding, dec!("300"));
+
+    offer_treaty(&mut test, &mut reinsurer, &ceding);
+
+    let receipt = test.pass_action(
+        &mut ceding,
+        ProposalAction::BuyReinsurance {
+            reinsurer: reinsurer.component,
+            treaty_id: 0,
+            premium: dec!("100"),
+        },
+    );
+
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::insurance::{CoveragePolicy, PolicyStatus};
+
+const MONTH: i64 = 30 * SECONDS_IN_DAY;
+
+fn pool_with_policy(test: &mut TestLedger) -> (Dao, ComponentAddress) {
+    let mut dao = test.instantiate_dao("renewal");
+    test.buy_tokens(&dao, dec!("400"));
+
+    test.create_funded_pool(&mut dao, "crop", dec!("200"));
+
+    test.purchase_policy(&dao, "crop", dec!("100"), MONTH)
+        .expect_commit_success();
+
+    let pool = test.pool_address(&dao, "crop");
+
+    (dao, pool)
+}
+
+fn status(test: &mut TestLedger, dao: &Dao) -> PolicyStatus {
+    test.get(
+        dao.component,
+        "get_policy_status",
+        manifest_args!("crop".to_string(), NonFungibleLocalId::integer(0)),
+    )
+}
+
+fn policy(test: &mut TestLedger, pool: ComponentAddress) -> CoveragePolicy {
+    test.get(pool, "get_policy", manifest_args!(NonFungibleLocalId::integer(0)))
+}
+
+fn renew(test: &mut TestLedger, dao: &Dao, pool: ComponentAddress) -> TransactionReceipt {
+    let account = test.account();
+
+    test.call_with_policy(dao, "crop", 0, |builder| {
+        builder
+            .withdraw_from_account(account, XRD, dec!("100"))
+            .take_all_from_worktop(XRD, "payment")
+            .call_method_with_name_lookup(pool, "renew_policy", |lookup| {
+                (lookup.proof("policy"), lookup.bucket("payment"), MONTH)
+            })
+    })
+}
+
+#[test]
+fn renewing_early_extends_the_cover_without_a_gap() {
+    let mut test = TestLedger::new();
+
+    let (dao, pool) = pool_with_policy(&mut test);
+
+    let end_time = policy(&mut test, pool).end_time;
+
+    test.advance_time(20 * SECONDS_IN_DAY);
+
+    renew(&mut test, &dao, pool).expect_commit_success();
+
+    let renewed = policy(&mut test, pool);
+    assert_eq!(renewed.end_time, end_time + MONTH);
+    assert!(renewed.uncovered_gaps.is_empty());
+    assert_eq!(status(&mut test, &dao), PolicyStatus::Active);
+}
+
+#[test]
+fn renewing_in_the_grace_period_leaves_the_gap_uncovered() {
+    let mut test = TestLedger::new();
+
+    let (mut dao, pool) = pool_with_policy(&mut test);
+
+    let end_time = policy(&mut test, pool).end_time;
+
+    test.advance_time(MONTH + 5 * SECONDS_IN_DAY);
+
+    assert_eq!(status(&mut test, &dao), PolicyStatus::GracePeriod);
+
+    renew(&mut test, &dao, pool).expect_commit_success();
+
+    let renewed_at = test.now_seconds();
+    let renewed = policy(&mut test, pool);
+
+    assert_eq!(renewed.end_time, renewed_at + MONTH);
+    assert_eq!(renewed.uncovered_gaps, vec![(end_time, renewed_at)]);
+    assert_eq!(status(&mut test, &dao), PolicyStatus::Active);
+
+    // an incident while the policy had expired is not covered
+    let receipt = test.try_propose(&dao, pay_policy_claim_action("crop", 0, dec!("50"), end_time + SECONDS_IN_DAY));
+    expect_failure_containing(&receipt, "the incident happened outside the covered period");
+
+    test.pass_action(&mut dao, pay_policy_claim_action("crop", 0, dec!("50"), renewed_at + 1))
+        .expect_commit_success();
+}
+
+#[test]
+fn lapsed_policies_can_not_be_renewed_and_free_their_capacity() {
+    let mut test = TestLedger::new();
+
+    let (dao, pool) = pool_with_policy(&mut test);
+
+    assert_eq!(test.pool_info(&dao, "crop").active_exposure, dec!("100"));
+
+    test.advance_time(MONTH + 15 * SECONDS_IN_DAY);
+
+    assert_eq!(status(&mut test, &dao), PolicyStatus::Lapsed);
+
+    let receipt = renew(&mut test, &dao, pool);
+    expect_failure_containing(&receipt, "this policy has lapsed and can no longer be renewed");
+
+    test.call(pool, "process_lapses", manifest_args!())
+        .expect_commit_success();
+
+    assert_eq!(test.pool_info(&dao, "crop").active_exposure, Decimal::zero());
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+
+// 200 XRD of cover in force against a pool funded with 100 XRD
+fn insured_dao(test: &mut TestLedger) -> Dao {
+    let mut dao = test.instantiate_dao("reserves");
+    test.buy_tokens(&dao, dec!("400"));
+
+    test.create_funded_pool(&mut dao, "crop", dec!("100"));
+
+    test.purchase_policy(&dao, "crop", dec!("200"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    dao
+}
+
+fn free_balance(test: &mut TestLedger, dao: &Dao) -> Decimal {
+    test.get(dao.component, "get_free_balance", manifest_args!(XRD))
+}
+
+#[test]
+fn ibnr_and_case_reserves_hold_back_treasury_funds() {
+    let mut test = TestLedger::new();
+
+    let mut dao = insured_dao(&mut test);
+
+    let solvency: Option<Decimal> = test.get(dao.component, "get_solvency_ratio", manifest_args!());
+    assert_eq!(solvency, None);
+
+    let free_before = free_balance(&mut test, &dao);
+
+    test.pass_action(&mut dao, ProposalAction::SetIbnrRatio(dec!("0.5")))
+        .expect_commit_success();
+
+    let ibnr: Decimal = test.get(dao.component, "get_ibnr_reserve", manifest_args!());
+    assert_eq!(ibnr, dec!("100"));
+    assert_eq!(free_balance(&mut test, &dao), free_before - dec!("100"));
+
+    // an open claim is reserved until it is paid or closed
+    let account = test.account();
+    test.propose(
+        &mut dao,
+        ProposalAction::PayClaim {
+            claimant: account,
+            amount: dec!("50"),
+        },
+    );
+
+    let reserves: Decimal = test.get(dao.component, "get_total_reserves", manifest_args!());
+    assert_eq!(reserves, dec!("150"));
+    assert_eq!(free_balance(&mut test, &dao), free_before - dec!("150"));
+
+    let treasury: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+    let solvency: Option<Decimal> = test.get(dao.component, "get_solvency_ratio", manifest_args!());
+    assert_eq!(solvency, Some(treasury / dec!("150")));
+}
+
+#[test]
+fn reserved_funds_can_not_be_spent() {
+    let mut test = TestLedger::new();
+
+    let mut dao = insured_dao(&mut test);
+
+    test.pass_action(&mut dao, ProposalAction::SetIbnrRatio(dec!("0.5")))
+        .expect_commit_success();
+
+    let recipient = test.new_actor();
+    let free = free_balance(&mut test, &dao);
+
+    let receipt = test.pass_action(
+        &mut dao,
+        ProposalAction::TransferAsset {
+            resource_address: XRD,
+            amount: free + dec!("1"),
+            recipient: recipient.account,
+        },
+    );
+    expect_failure_containing(&receipt, "only funds above the treasury reserves can be used");
+
+    let recipient_before = test.xrd_balance(recipient.account);
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::TransferAsset {
+            resource_address: XRD,
+            amount: free,
+            recipient: recipient.account,
+        },
+    )
+    .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(recipient.account), recipient_before + free);
+    assert_eq!(free_balance(&mut test, &dao), Decimal::zero());
+
+    let ibnr: Decimal = test.get(dao.component, "get_ibnr_reserve", manifest_args!());
+    assert_eq!(ibnr, dec!("100"));
+}
+
+#[test]
+fn the_ibnr_ratio_is_a_share_of_cover() {
+    let mut test = TestLedger::new();
+
+    let dao = insured_dao(&mut test);
+
+    let receipt = test.try_propose(&dao, ProposalAction::SetIbnrRatio(dec!("1.5")));
+    expect_failure_containing(&receipt, "IBNR ratio must be between 0 and 1");
+}
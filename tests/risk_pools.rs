@@ -0,0 +1,98 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+use actus::insurance::PolicyTransferability;
+
+#[test]
+fn claims_are_paid_from_their_own_pool_only() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("pools");
+    test.buy_tokens(&dao, dec!("600"));
+
+    test.create_funded_pool(&mut dao, "crop", dec!("200"));
+    test.create_funded_pool(&mut dao, "flight", dec!("300"));
+
+    test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let crop_before = test.pool_info(&dao, "crop");
+    let flight_before = test.pool_info(&dao, "flight");
+    let treasury_before: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+
+    // the premium stays with the pool that wrote the policy
+    assert!(crop_before.capital > dec!("200"));
+    assert_eq!(flight_before.capital, dec!("300"));
+
+    let account = test.account();
+    let account_before = test.xrd_balance(account);
+    let incident_time = test.now_seconds();
+
+    test.pass_action(&mut dao, pay_policy_claim_action("crop", 0, dec!("50"), incident_time))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(account), account_before + dec!("50"));
+
+    let crop = test.pool_info(&dao, "crop");
+    assert_eq!(crop.capital, crop_before.capital - dec!("50"));
+    assert_eq!(crop.total_claims_paid, dec!("50"));
+
+    assert_eq!(test.pool_info(&dao, "flight").capital, dec!("300"));
+
+    let treasury: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+    assert_eq!(treasury, treasury_before);
+}
+
+#[test]
+fn pool_capital_moves_back_to_the_treasury_through_defunding() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("pools");
+    test.buy_tokens(&dao, dec!("600"));
+
+    test.create_funded_pool(&mut dao, "crop", dec!("200"));
+
+    let treasury_before: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::DefundRiskPool {
+            name: "crop".to_string(),
+            amount: dec!("150"),
+        },
+    )
+    .expect_commit_success();
+
+    assert_eq!(test.pool_info(&dao, "crop").capital, dec!("50"));
+
+    let treasury: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+    assert_eq!(treasury, treasury_before + dec!("150"));
+}
+
+#[test]
+fn pools_are_addressed_by_unique_names() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("pools");
+    test.buy_tokens(&dao, dec!("600"));
+
+    test.create_funded_pool(&mut dao, "crop", dec!("200"));
+
+    let receipt = test.try_propose(
+        &dao,
+        create_pool_action("crop", default_terms(), default_underwriting(), None, PolicyTransferability::Transferable),
+    );
+    expect_failure_containing(&receipt, "a risk pool with this name already exists");
+
+    let receipt = test.try_propose(
+        &dao,
+        ProposalAction::FundRiskPool {
+            name: "marine".to_string(),
+            amount: dec!("100"),
+        },
+    );
+    expect_failure_containing(&receipt, "no risk pool with this name");
+}
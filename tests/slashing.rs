@@ -0,0 +1,138 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::bonding_curve::{BondingCurve, BondingCurveShape};
+use actus::events::{ProposalAction, RedemptionRequest};
+use actus::staking::StakePosition;
+
+fn staker(test: &mut TestLedger, dao: &Dao, amount: Decimal) -> Actor {
+    let actor = test.new_actor();
+
+    test.buy_tokens_as(&actor, dao, amount);
+    test.stake_as(&actor, dao, amount).expect_commit_success();
+
+    actor
+}
+
+fn staked_amount(test: &mut TestLedger, dao: &Dao, staker: &Actor) -> Decimal {
+    let position: Option<StakePosition> = test.get(dao.component, "get_stake", manifest_args!(staker.account));
+
+    position.unwrap().staked_amount
+}
+
+#[test]
+fn curve_mode_slashes_sell_the_stake_for_the_claimant_right_away() {
+    let mut test = TestLedger::new();
+
+    // every XRD paid for tokens backs buy-backs, the treasury keeps nothing
+    let curve = BondingCurve {
+        shape: BondingCurveShape::Linear { slope: Decimal::zero() },
+        base_price: dec!("1"),
+        reserve_ratio: dec!("1"),
+    };
+
+    let mut dao = test.instantiate_dao_with_curve("slashing", Some(curve));
+    test.buy_tokens(&dao, dec!("20"));
+
+    let staker = staker(&mut test, &dao, dec!("100"));
+    let claimant = test.new_actor();
+
+    let claimant_before = test.xrd_balance(claimant.account);
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::PayClaim {
+            claimant: claimant.account,
+            amount: dec!("50"),
+        },
+    )
+    .expect_commit_success();
+
+    // the empty treasury pays nothing, half the stake is sold back along the curve
+    assert_eq!(test.xrd_balance(claimant.account), claimant_before + dec!("50"));
+    assert_eq!(staked_amount(&mut test, &dao, &staker), dec!("50"));
+
+    let queue: Vec<RedemptionRequest> = test.get(dao.component, "get_redemption_queue", manifest_args!());
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn fixed_price_slashes_are_paid_from_the_next_token_sales() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("slashing");
+    test.buy_tokens(&dao, dec!("20"));
+
+    let staker = staker(&mut test, &dao, dec!("100"));
+    let claimant = test.new_actor();
+
+    let claimant_before = test.xrd_balance(claimant.account);
+
+    // the treasury holds 120 XRD, the 80 XRD shortfall is slashed from the stake
+    test.pass_action(
+        &mut dao,
+        ProposalAction::PayClaim {
+            claimant: claimant.account,
+            amount: dec!("200"),
+        },
+    )
+    .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(claimant.account), claimant_before + dec!("120"));
+    assert_eq!(staked_amount(&mut test, &dao, &staker), dec!("20"));
+
+    let queue: Vec<RedemptionRequest> = test.get(dao.component, "get_redemption_queue", manifest_args!());
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue[0].amount_owed, dec!("80"));
+
+    // the slashed tokens are the next ones sold, their proceeds go to the claimant
+    let buyer = test.new_actor();
+    test.buy_tokens_as(&buyer, &dao, dec!("80"));
+
+    let queue: Vec<RedemptionRequest> = test.get(dao.component, "get_redemption_queue", manifest_args!());
+    assert!(queue.is_empty());
+
+    test.call_with_account_as(&claimant, &dao, "claim_redemption")
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(claimant.account), claimant_before + dec!("200"));
+}
+
+#[test]
+fn stable_asset_stays_xrd_while_stakes_back_claims() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("slashing");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let account = test.account();
+    let usd = test.ledger.create_fungible_resource(dec!("1000"), 18, account);
+
+    test.pass_action(&mut dao, ProposalAction::WhitelistAsset(usd))
+        .expect_commit_success();
+
+    let staker = staker(&mut test, &dao, dec!("10"));
+
+    let receipt = test.try_propose(&dao, ProposalAction::SetStableAsset(usd));
+    expect_failure_containing(&receipt, "claims must stay in XRD while staked capital backs them");
+
+    // once the stake is gone the DAO may switch, and staking closes
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(dao.component, "unstake", manifest_args!(dec!("10"), staker.account))
+        .build();
+    test.execute_as(&staker, manifest).expect_commit_success();
+
+    test.advance_time(7 * SECONDS_IN_DAY);
+
+    test.call_with_account_as(&staker, &dao, "withdraw_unstaked")
+        .expect_commit_success();
+
+    test.pass_action(&mut dao, ProposalAction::SetStableAsset(usd))
+        .expect_commit_success();
+
+    let receipt = test.stake_as(&staker, &dao, dec!("10"));
+    expect_failure_containing(&receipt, "staking is only open while claims are paid in XRD");
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+use actus::staking::StakePosition;
+
+// half of every premium goes to stakers, unstaked tokens wait a day
+fn staking_dao(test: &mut TestLedger) -> Dao {
+    let mut dao = test.instantiate_dao("staking");
+    test.buy_tokens(&dao, dec!("100"));
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::ConfigureStaking {
+            reward_share: dec!("0.5"),
+            cooldown_seconds: SECONDS_IN_DAY,
+        },
+    )
+    .expect_commit_success();
+
+    dao
+}
+
+fn staker(test: &mut TestLedger, dao: &Dao, amount: Decimal) -> Actor {
+    let actor = test.new_actor();
+
+    test.buy_tokens_as(&actor, dao, amount);
+    test.stake_as(&actor, dao, amount).expect_commit_success();
+
+    actor
+}
+
+fn unstake(test: &mut TestLedger, actor: &Actor, dao: &Dao, amount: Decimal) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(dao.component, "unstake", manifest_args!(amount, actor.account))
+        .build();
+
+    test.execute_as(actor, manifest)
+}
+
+#[test]
+fn premiums_are_shared_with_stakers_pro_rata() {
+    let mut test = TestLedger::new();
+
+    let dao = staking_dao(&mut test);
+
+    let first = staker(&mut test, &dao, dec!("30"));
+    let second = staker(&mut test, &dao, dec!("10"));
+
+    let treasury_before: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+
+    test.pay_premium(&dao, dec!("80")).expect_commit_success();
+
+    let treasury: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+    assert_eq!(treasury, treasury_before + dec!("40"));
+
+    let position: Option<StakePosition> = test.get(dao.component, "get_stake", manifest_args!(first.account));
+    assert_eq!(position.unwrap().accrued_rewards[&XRD], dec!("30"));
+
+    let first_before = test.xrd_balance(first.account);
+    let second_before = test.xrd_balance(second.account);
+
+    test.call_with_account_as(&first, &dao, "claim_rewards").expect_commit_success();
+    test.call_with_account_as(&second, &dao, "claim_rewards").expect_commit_success();
+
+    assert_eq!(test.xrd_balance(first.account), first_before + dec!("30"));
+    assert_eq!(test.xrd_balance(second.account), second_before + dec!("10"));
+}
+
+#[test]
+fn unstaked_tokens_stop_earning_and_wait_out_the_cooldown() {
+    let mut test = TestLedger::new();
+
+    let dao = staking_dao(&mut test);
+
+    let first = staker(&mut test, &dao, dec!("30"));
+    let second = staker(&mut test, &dao, dec!("10"));
+
+    unstake(&mut test, &first, &dao, dec!("30")).expect_commit_success();
+
+    // only the remaining stake earns from this premium
+    test.pay_premium(&dao, dec!("80")).expect_commit_success();
+
+    let position: Option<StakePosition> = test.get(dao.component, "get_stake", manifest_args!(second.account));
+    assert_eq!(position.unwrap().accrued_rewards[&XRD], dec!("40"));
+
+    let receipt = test.call_with_account_as(&first, &dao, "withdraw_unstaked");
+    expect_failure_containing(&receipt, "the unstaking cooldown has not passed yet");
+
+    test.advance_time(SECONDS_IN_DAY);
+
+    test.call_with_account_as(&first, &dao, "withdraw_unstaked").expect_commit_success();
+
+    assert_eq!(test.balance(first.account, dao.token), dec!("30"));
+}
+
+#[test]
+fn stakers_can_not_unstake_more_than_they_staked() {
+    let mut test = TestLedger::new();
+
+    let dao = staking_dao(&mut test);
+
+    let first = staker(&mut test, &dao, dec!("30"));
+
+    let receipt = unstake(&mut test, &first, &dao, dec!("31"));
+    expect_failure_containing(&receipt, "can not unstake more than is staked");
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::ProposalAction;
+
+// a fee-free XRD/USD pool holding 1000 of each
+fn instantiate_pool(test: &mut TestLedger, usd: ResourceAddress) -> ComponentAddress {
+    let account = test.account();
+    let package = test.package;
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!("1000"))
+        .withdraw_from_account(account, usd, dec!("1000"))
+        .take_all_from_worktop(XRD, "xrd")
+        .take_all_from_worktop(usd, "usd")
+        .call_function_with_name_lookup(package, "SwapPool", "instantiate_swap_pool", |lookup| {
+            (lookup.bucket("xrd"), lookup.bucket("usd"), Decimal::zero())
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    test.execute(manifest).expect_commit_success().new_component_addresses()[0]
+}
+
+fn rebalance(pool: ComponentAddress, min_output: Decimal) -> ProposalAction {
+    ProposalAction::RebalanceTreasury {
+        pool,
+        input_resource: XRD,
+        input_amount: dec!("100"),
+        min_output,
+    }
+}
+
+// a DAO with 300 XRD that has whitelisted USD and the pool
+fn setup(test: &mut TestLedger) -> (Dao, ResourceAddress, ComponentAddress) {
+    let mut dao = test.instantiate_dao("swap");
+    test.buy_tokens(&dao, dec!("300"));
+
+    let account = test.account();
+    let usd = test.ledger.create_fungible_resource(dec!("10000"), 18, account);
+    let pool = instantiate_pool(test, usd);
+
+    test.pass_action(&mut dao, ProposalAction::WhitelistAsset(usd))
+        .expect_commit_success();
+    test.pass_action(&mut dao, ProposalAction::WhitelistSwapPool(pool))
+        .expect_commit_success();
+
+    (dao, usd, pool)
+}
+
+#[test]
+fn treasury_swaps_through_a_whitelisted_pool() {
+    let mut test = TestLedger::new();
+
+    let (mut dao, usd, pool) = setup(&mut test);
+
+    let expected: Decimal = test.get(pool, "get_amount_out", manifest_args!(XRD, dec!("100")));
+    let xrd_before: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+
+    test.pass_action(&mut dao, rebalance(pool, dec!("90")))
+        .expect_commit_success();
+
+    let usd_held: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(usd));
+    assert_eq!(usd_held, expected);
+
+    let xrd_after: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(XRD));
+    assert_eq!(xrd_after, xrd_before - dec!("100"));
+}
+
+#[test]
+fn swaps_below_the_minimum_output_are_aborted() {
+    let mut test = TestLedger::new();
+
+    let (mut dao, usd, pool) = setup(&mut test);
+
+    // 100 XRD only buys about 90.9 USD from the pool
+    let receipt = test.pass_action(&mut dao, rebalance(pool, dec!("95")));
+    expect_failure_containing(&receipt, "swap output is below the minimum the proposal allows");
+
+    let usd_held: Decimal = test.get(dao.component, "treasury_balance", manifest_args!(usd));
+    assert_eq!(usd_held, Decimal::zero());
+}
+
+#[test]
+fn only_whitelisted_pools_and_assets_can_be_used() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("swap");
+    test.buy_tokens(&dao, dec!("300"));
+
+    let account = test.account();
+    let usd = test.ledger.create_fungible_resource(dec!("10000"), 18, account);
+    let pool = instantiate_pool(&mut test, usd);
+
+    let receipt = test.try_propose(&dao, rebalance(pool, dec!("90")));
+    expect_failure_containing(&receipt, "only whitelisted swap pools can be used");
+
+    // USD is not whitelisted yet
+    let receipt = test.try_propose(&dao, ProposalAction::WhitelistSwapPool(pool));
+    expect_failure_containing(&receipt, "a swap pool may only trade whitelisted assets");
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::events::{ContributorRecord, TreasuryReport};
+
+#[test]
+fn contributions_land_in_the_treasury_and_its_report() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("treasury");
+
+    let before: TreasuryReport = test.get(dao.component, "get_treasury_report", manifest_args!());
+    let inflow_before = before.inflows.get(&XRD).cloned().unwrap_or_default();
+
+    test.contribute(&dao, dec!("40")).expect_commit_success();
+    test.contribute(&dao, dec!("60")).expect_commit_success();
+
+    let report: TreasuryReport = test.get(dao.component, "get_treasury_report", manifest_args!());
+
+    assert_eq!(report.balances[&XRD], before.balances[&XRD] + dec!("100"));
+    assert_eq!(report.inflows[&XRD], inflow_before + dec!("100"));
+    assert_eq!(test.xrd_balance(dao.component), report.balances[&XRD]);
+
+    let records: HashMap<ComponentAddress, ContributorRecord> =
+        test.get(dao.component, "get_contributor_records", manifest_args!());
+    let record = &records[&test.account()];
+
+    assert_eq!(record.total_contributed, dec!("100"));
+    assert_eq!(record.number_of_contributions, 2);
+}
+
+#[test]
+fn only_xrd_is_accepted_as_a_contribution() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("treasury");
+    test.buy_tokens(&dao, dec!("10"));
+
+    let account = test.account();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, dao.token, dec!("5"))
+        .take_all_from_worktop(dao.token, "tokens")
+        .call_method_with_name_lookup(dao.component, "send_money_to_dao_treasury", |lookup| {
+            (lookup.bucket("tokens"), account)
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    let receipt = test.execute(manifest);
+
+    expect_failure_containing(&receipt, "Only XRD tokens are accepted for treasury contributions");
+
+    let records: HashMap<ComponentAddress, ContributorRecord> =
+        test.get(dao.component, "get_contributor_records", manifest_args!());
+    assert!(records.is_empty());
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::insurance::{PolicyTransferability, UnderwritingRules};
+
+fn pool_with_rules(test: &mut TestLedger, underwriting: UnderwritingRules) -> Dao {
+    let mut dao = test.instantiate_dao("underwriting");
+    test.buy_tokens(&dao, dec!("400"));
+
+    test.pass_action(
+        &mut dao,
+        create_pool_action("crop", default_terms(), underwriting, None, PolicyTransferability::Transferable),
+    )
+    .expect_commit_success();
+
+    test.fund_pool(&mut dao, "crop", dec!("300"));
+
+    dao
+}
+
+#[test]
+fn incidents_in_the_waiting_period_are_not_covered() {
+    let mut test = TestLedger::new();
+
+    let mut dao = pool_with_rules(
+        &mut test,
+        UnderwritingRules {
+            waiting_period_seconds: 7 * SECONDS_IN_DAY,
+            ..default_underwriting()
+        },
+    );
+
+    test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let incident_time = test.now_seconds();
+    let receipt = test.try_propose(&dao, pay_policy_claim_action("crop", 0, dec!("50"), incident_time));
+    expect_failure_containing(&receipt, "the incident happened outside the covered period");
+
+    test.advance_time(7 * SECONDS_IN_DAY);
+
+    let account = test.account();
+    let account_before = test.xrd_balance(account);
+
+    let incident_time = test.now_seconds();
+    test.pass_action(&mut dao, pay_policy_claim_action("crop", 0, dec!("50"), incident_time))
+        .expect_commit_success();
+
+    assert_eq!(test.xrd_balance(account), account_before + dec!("50"));
+}
+
+#[test]
+fn accounts_can_only_buy_so_much_cover_per_window() {
+    let mut test = TestLedger::new();
+
+    let dao = pool_with_rules(
+        &mut test,
+        UnderwritingRules {
+            max_cover_per_account: dec!("150"),
+            ..default_underwriting()
+        },
+    );
+
+    test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    let receipt = test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR);
+    expect_failure_containing(&receipt, "this account has reached its cover limit for the current window");
+
+    test.purchase_policy(&dao, "crop", dec!("50"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+
+    // windows are 10 epochs long, the next one starts with a fresh allowance
+    let next_window = (test.ledger.get_current_epoch().number() / 10 + 1) * 10;
+    test.ledger.set_current_epoch(Epoch::of(next_window));
+
+    test.purchase_policy(&dao, "crop", dec!("100"), SECONDS_IN_YEAR)
+        .expect_commit_success();
+}
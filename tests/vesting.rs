@@ -0,0 +1,148 @@
+mod common;
+
+use common::*;
+use scrypto_test::prelude::*;
+
+use actus::bonding_curve::{BondingCurve, BondingCurveShape};
+use actus::events::ProposalAction;
+use actus::vesting::VestingAllocation;
+
+// 100 tokens vesting over 100 days behind a 10 day cliff
+fn allocation(beneficiary: ComponentAddress) -> VestingAllocation {
+    VestingAllocation {
+        beneficiary,
+        amount: dec!("100"),
+        cliff_seconds: 10 * SECONDS_IN_DAY,
+        vesting_seconds: 100 * SECONDS_IN_DAY,
+    }
+}
+
+fn claim_vested(test: &mut TestLedger, beneficiary: &Actor, dao: &Dao) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_non_fungibles_from_account(
+            beneficiary.account,
+            dao.vesting_receipt,
+            [NonFungibleLocalId::integer(0)],
+        )
+        .take_all_from_worktop(dao.vesting_receipt, "receipt")
+        .call_method_with_name_lookup(dao.component, "claim_vested_tokens", |lookup| {
+            (lookup.bucket("receipt"),)
+        })
+        .try_deposit_entire_worktop_or_abort(beneficiary.account, None)
+        .build();
+
+    test.execute_as(beneficiary, manifest)
+}
+
+#[test]
+fn vested_tokens_are_released_linearly_after_the_cliff() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("vesting");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let beneficiary = test.new_actor();
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::MintVested {
+            allocations: vec![allocation(beneficiary.account)],
+        },
+    )
+    .expect_commit_success();
+
+    assert_eq!(test.balance(beneficiary.account, dao.vesting_receipt), dec!("1"));
+    assert_eq!(test.balance(beneficiary.account, dao.token), Decimal::zero());
+
+    test.advance_time(50 * SECONDS_IN_DAY);
+
+    claim_vested(&mut test, &beneficiary, &dao).expect_commit_success();
+
+    assert_eq!(test.balance(beneficiary.account, dao.token), dec!("50"));
+    assert_eq!(test.balance(beneficiary.account, dao.vesting_receipt), dec!("1"));
+
+    test.advance_time(100 * SECONDS_IN_DAY);
+
+    claim_vested(&mut test, &beneficiary, &dao).expect_commit_success();
+
+    assert_eq!(test.balance(beneficiary.account, dao.token), dec!("100"));
+}
+
+#[test]
+fn nothing_can_be_claimed_before_the_cliff() {
+    let mut test = TestLedger::new();
+
+    let mut dao = test.instantiate_dao("vesting");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let beneficiary = test.new_actor();
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::MintVested {
+            allocations: vec![allocation(beneficiary.account)],
+        },
+    )
+    .expect_commit_success();
+
+    test.advance_time(9 * SECONDS_IN_DAY);
+
+    let receipt = claim_vested(&mut test, &beneficiary, &dao);
+    expect_failure_containing(&receipt, "nothing has vested since the last claim");
+
+    assert_eq!(test.balance(beneficiary.account, dao.token), Decimal::zero());
+}
+
+#[test]
+fn cliffs_beyond_the_vesting_period_are_rejected() {
+    let mut test = TestLedger::new();
+
+    let dao = test.instantiate_dao("vesting");
+    test.buy_tokens(&dao, dec!("100"));
+
+    let mut allocation = allocation(test.account());
+    allocation.cliff_seconds = 200 * SECONDS_IN_DAY;
+
+    let receipt = test.try_propose(
+        &dao,
+        ProposalAction::MintVested {
+            allocations: vec![allocation],
+        },
+    );
+
+    expect_failure_containing(&receipt, "the cliff must fall within the vesting period");
+}
+
+#[test]
+fn vested_mints_fund_their_share_of_the_curve_reserve() {
+    let mut test = TestLedger::new();
+
+    let curve = BondingCurve {
+        shape: BondingCurveShape::Linear { slope: Decimal::zero() },
+        base_price: dec!("1"),
+        reserve_ratio: dec!("0.5"),
+    };
+
+    let mut dao = test.instantiate_dao_with_curve("vesting", Some(curve));
+    test.buy_tokens(&dao, dec!("200"));
+
+    let reserve_before: Decimal = test.get(dao.component, "get_curve_reserve", manifest_args!());
+
+    let beneficiary = test.new_actor();
+
+    test.pass_action(
+        &mut dao,
+        ProposalAction::MintVested {
+            allocations: vec![allocation(beneficiary.account)],
+        },
+    )
+    .expect_commit_success();
+
+    // the treasury moves half the price of 100 tokens into the reserve
+    let reserve: Decimal = test.get(dao.component, "get_curve_reserve", manifest_args!());
+    assert_eq!(reserve, reserve_before + dec!("50"));
+
+    let value: Decimal = test.get(dao.component, "get_buy_back_value", manifest_args!(dec!("300")));
+    assert_eq!(value, reserve);
+}